@@ -0,0 +1,127 @@
+// Counting global allocator that captures a backtrace for every large allocation
+// and exports them as folded stacks for inferno / flamegraph.pl
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    backtrace::Backtrace,
+    cell::Cell,
+    collections::HashMap,
+    io::{self, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+use crate::core::lock_recovering;
+
+// Allocations at least this large get a backtrace; smaller ones are only counted
+pub const PROFILE_THRESHOLD: usize = 1024 * 1024;
+
+pub static ALLOCATION_COUNT: AtomicUsize = AtomicUsize::new(0);
+pub static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+// Unresolved backtraces; symbols are only resolved at export time
+static RECORDS: Mutex<Vec<(Backtrace, usize)>> = Mutex::new(Vec::new());
+
+thread_local! {
+    // Set while recording so allocations made by the recorder itself pass through
+    static IN_RECORD: Cell<bool> = const { Cell::new(false) };
+}
+
+pub struct ProfilingAllocator;
+
+#[global_allocator]
+static GLOBAL: ProfilingAllocator = ProfilingAllocator;
+
+fn record(size: usize) {
+    ALLOCATION_COUNT.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+    if size < PROFILE_THRESHOLD {
+        return;
+    }
+
+    // try_with: TLS may already be gone while a thread is exiting
+    let _ = IN_RECORD.try_with(|in_record| {
+        if in_record.replace(true) {
+            return;
+        }
+        let backtrace = Backtrace::force_capture();
+        lock_recovering(&RECORDS, "allocation profile").push((backtrace, size));
+        in_record.set(false);
+    });
+}
+
+unsafe impl GlobalAlloc for ProfilingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() && new_size > layout.size() {
+            record(new_size - layout.size());
+        }
+        new_ptr
+    }
+}
+
+// Frame names from a resolved backtrace, root first, without the allocator's own frames
+fn folded_frames(backtrace: &Backtrace) -> Vec<String> {
+    let rendered = backtrace.to_string();
+    let mut frames: Vec<String> = rendered
+        .lines()
+        .filter_map(|line| {
+            let (index, name) = line.trim_start().split_once(": ")?;
+            index.parse::<usize>().ok()?;
+            Some(name.trim().replace(';', ":"))
+        })
+        .skip_while(|name| {
+            name.contains("alloc_profile::") || name.contains("Backtrace") || name.contains("__rust_")
+        })
+        .collect();
+    frames.reverse();
+    frames
+}
+
+/// Drain recorded allocations into `path` as `frame;frame;frame bytes` lines
+/// Returns the number of distinct stacks written
+pub fn export(path: &Path) -> io::Result<usize> {
+    let records = {
+        let mut records = lock_recovering(&RECORDS, "allocation profile");
+        std::mem::take(&mut *records)
+    };
+
+    let mut folded: HashMap<String, usize> = HashMap::new();
+    for (backtrace, size) in &records {
+        let frames = folded_frames(backtrace);
+        let stack = if frames.is_empty() { "[unknown]".to_string() } else { frames.join(";") };
+        *folded.entry(stack).or_insert(0) += size;
+    }
+
+    let mut lines: Vec<_> = folded.into_iter().collect();
+    lines.sort();
+
+    let mut file = io::BufWriter::new(std::fs::File::create(path)?);
+    for (stack, bytes) in &lines {
+        writeln!(file, "{} {}", stack, bytes)?;
+    }
+    file.flush()?;
+    Ok(lines.len())
+}
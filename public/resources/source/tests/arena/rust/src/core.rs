@@ -1,10 +1,60 @@
 // Core functionality shared between main.rs and lib.rs
 use std::{fs, thread, time::{Duration, Instant}};
+use std::sync::{Arc, LazyLock, Mutex, MutexGuard, atomic::{AtomicBool, AtomicUsize, Ordering}};
 
 pub const DEFAULT_THREAD_COUNT: usize = 1280000;
 pub const ALLOCS_PER_THREAD: usize = 1;
 pub const ALLOC_SIZE: usize = 64 * 1024 * 1024;   // 64 MiB
 
+// Lock global state, recovering the data if a previous holder panicked,
+// so one panic doesn't break every later call into the module
+pub fn lock_recovering<'a, T>(mutex: &'a Mutex<T>, name: &str) -> MutexGuard<'a, T> {
+    mutex.lock().unwrap_or_else(|poisoned| {
+        eprintln!("Warning: {} lock was poisoned by a panic; recovering its state", name);
+        mutex.clear_poison();
+        poisoned.into_inner()
+    })
+}
+
+// Tunables the arena test actually runs with; they start at the defaults above
+// and are replaced wholesale when a load profile is applied
+#[derive(Debug, Clone)]
+pub struct ArenaConfig {
+    pub profile: &'static str,
+    pub thread_count: usize,
+    pub allocs_per_thread: usize,
+    pub alloc_size: usize,
+    // (size, keep) script for the fragmentation pattern runner; empty if unused
+    pub pattern: Vec<(usize, bool)>,
+}
+
+impl Default for ArenaConfig {
+    fn default() -> Self {
+        Self {
+            profile: "default",
+            thread_count: DEFAULT_THREAD_COUNT,
+            allocs_per_thread: ALLOCS_PER_THREAD,
+            alloc_size: ALLOC_SIZE,
+            pattern: Vec::new(),
+        }
+    }
+}
+
+static CONFIG: LazyLock<Mutex<ArenaConfig>> = LazyLock::new(|| Mutex::new(ArenaConfig::default()));
+
+pub fn config() -> ArenaConfig {
+    lock_recovering(&CONFIG, "arena config").clone()
+}
+
+// Just the allocation size, without cloning the whole config (and its pattern)
+pub fn config_alloc_size() -> usize {
+    lock_recovering(&CONFIG, "arena config").alloc_size
+}
+
+pub fn update_config(update: impl FnOnce(&mut ArenaConfig)) {
+    update(&mut lock_recovering(&CONFIG, "arena config"));
+}
+
 #[derive(Debug, Clone)]
 pub struct MemoryStats {
     pub vm_rss_kb: u64,
@@ -84,22 +134,65 @@ pub fn get_thread_count() -> usize {
     1
 }
 
-pub fn worker() {
-    for _ in 0..ALLOCS_PER_THREAD {
-        let mut v = Vec::<u8>::with_capacity(ALLOC_SIZE);
-        unsafe { v.set_len(ALLOC_SIZE); }
+// Cancellation token shared by all workers; set it to make them stop early
+static ABORT_TOKEN: LazyLock<Arc<AtomicBool>> = LazyLock::new(|| Arc::new(AtomicBool::new(false)));
+
+pub fn abort_token() -> Arc<AtomicBool> {
+    Arc::clone(&ABORT_TOKEN)
+}
+
+pub fn worker(allocs: usize, alloc_size: usize, abort: &AtomicBool, done: &AtomicUsize) {
+    for _ in 0..allocs {
+        if abort.load(Ordering::Relaxed) {
+            break;
+        }
+        let mut v = Vec::<u8>::with_capacity(alloc_size);
+        unsafe { v.set_len(alloc_size); }
         drop(v);
+        done.fetch_add(1, Ordering::Relaxed);
     }
 }
 
-pub fn task(thread_count: usize) {
+// Returns the number of allocations performed
+pub fn task(thread_count: usize) -> usize {
+    task_with_allocs(thread_count, config().allocs_per_thread)
+}
+
+// Returns the number of allocations actually performed (fewer if aborted)
+// Clears any earlier abort request first so a stale one can't empty this run
+pub fn task_with_allocs(thread_count: usize, allocs_per_thread: usize) -> usize {
+    let abort = abort_token();
+    abort.store(false, Ordering::Relaxed);
+    let alloc_size = config_alloc_size();
+    let done = Arc::new(AtomicUsize::new(0));
     let mut ths = Vec::with_capacity(thread_count);
     for _ in 0..thread_count {
-        ths.push(thread::spawn(worker));
+        let abort = Arc::clone(&abort);
+        let done = Arc::clone(&done);
+        ths.push(thread::spawn(move || worker(allocs_per_thread, alloc_size, &abort, &done)));
     }
     for th in ths {
         th.join().unwrap();
     }
+    done.load(Ordering::Relaxed)
+}
+
+// Replay a (size, keep) script; returns the kept blocks plus kept and freed byte totals
+pub fn replay_pattern(pattern: &[(usize, bool)]) -> (Vec<Vec<u8>>, usize, usize) {
+    let mut kept: Vec<Vec<u8>> = Vec::new();
+    let mut kept_bytes = 0usize;
+    let mut freed_bytes = 0usize;
+    for &(size, keep) in pattern {
+        let block = vec![1u8; size];
+        if keep {
+            kept_bytes += size;
+            kept.push(block);
+        } else {
+            freed_bytes += size;
+            drop(block);
+        }
+    }
+    (kept, kept_bytes, freed_bytes)
 }
 
 pub fn print_rss(tag: &str) {
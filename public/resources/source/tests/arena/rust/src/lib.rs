@@ -1,9 +1,18 @@
 use pyo3::prelude::*;
 use std::{fs, thread, time::{Duration, Instant}};
+use std::sync::{Arc, atomic::{AtomicBool, AtomicUsize, Ordering}};
 
 mod core;
 use core::*;
 
+mod profiles;
+
+#[cfg(unix)]
+mod stats_server;
+
+#[cfg(feature = "alloc-profile")]
+mod alloc_profile;
+
 // All core functions are now imported from core.rs
 
 /// Get current RSS memory usage in MiB
@@ -18,10 +27,18 @@ fn get_rss_kib() -> PyResult<u64> {
     Ok(rss_kib())
 }
 
+// Explicit thread count, or the active profile's when the caller passes None
+fn resolve_thread_count(thread_count: Option<usize>) -> usize {
+    thread_count.unwrap_or_else(|| config().thread_count)
+}
+
 /// Run the arena allocation test with specified thread count
+/// (default: the active profile's)
 /// Returns a tuple of (initial_rss_mib, final_rss_mib)
 #[pyfunction]
-fn run_arena_test(thread_count: usize) -> PyResult<(f64, f64)> {
+#[pyo3(signature = (thread_count=None))]
+fn run_arena_test(thread_count: Option<usize>) -> PyResult<(f64, f64)> {
+    let thread_count = resolve_thread_count(thread_count);
     let initial_rss = rss_kib() as f64 / 1024.0;
     
     // Run the task
@@ -35,7 +52,9 @@ fn run_arena_test(thread_count: usize) -> PyResult<(f64, f64)> {
 /// Run the arena allocation test with monitoring
 /// Returns a dictionary with detailed information
 #[pyfunction]
-fn run_arena_test_detailed(thread_count: usize, sleep_seconds: Option<u64>) -> PyResult<PyObject> {
+#[pyo3(signature = (thread_count=None, sleep_seconds=None))]
+fn run_arena_test_detailed(thread_count: Option<usize>, sleep_seconds: Option<u64>) -> PyResult<PyObject> {
+    let thread_count = resolve_thread_count(thread_count);
     Python::with_gil(|py| {
         let dict = pyo3::types::PyDict::new(py);
         
@@ -45,11 +64,13 @@ fn run_arena_test_detailed(thread_count: usize, sleep_seconds: Option<u64>) -> P
         dict.set_item("pid", pid)?;
         dict.set_item("thread_count", thread_count)?;
         dict.set_item("initial_rss_mib", initial_rss)?;
-        dict.set_item("allocs_per_thread", ALLOCS_PER_THREAD)?;
-        dict.set_item("alloc_size_bytes", ALLOC_SIZE)?;
+        let config = config();
+        dict.set_item("allocs_per_thread", config.allocs_per_thread)?;
+        dict.set_item("alloc_size_bytes", config.alloc_size)?;
         
         // Run the task
-        task(thread_count);
+        let allocations = task(thread_count);
+        dict.set_item("allocations", allocations)?;
         
         let after_task_rss = rss_kib() as f64 / 1024.0;
         dict.set_item("after_task_rss_mib", after_task_rss)?;
@@ -65,12 +86,407 @@ fn run_arena_test_detailed(thread_count: usize, sleep_seconds: Option<u64>) -> P
     })
 }
 
+/// Run the arena allocation test while sampling the process thread count
+/// Reports the peak number of concurrently alive threads versus the requested
+/// count; a large gap means the worker spawns effectively serialized
+#[pyfunction]
+#[pyo3(signature = (thread_count=None, allocs_per_thread=None))]
+fn run_arena_test_thread_peak(thread_count: Option<usize>, allocs_per_thread: Option<usize>) -> PyResult<PyObject> {
+    let thread_count = resolve_thread_count(thread_count);
+    let allocs_per_thread = allocs_per_thread.unwrap_or_else(|| config().allocs_per_thread);
+    Python::with_gil(|py| {
+        let dict = pyo3::types::PyDict::new(py);
+        let baseline_threads = get_thread_count();
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let peak = Arc::new(AtomicUsize::new(baseline_threads));
+        let sampler = {
+            let stop = Arc::clone(&stop);
+            let peak = Arc::clone(&peak);
+            thread::spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    peak.fetch_max(get_thread_count(), Ordering::Relaxed);
+                    thread::sleep(Duration::from_millis(1));
+                }
+            })
+        };
+
+        py.allow_threads(|| task_with_allocs(thread_count, allocs_per_thread));
+
+        stop.store(true, Ordering::Relaxed);
+        sampler.join().unwrap();
+
+        // Exclude the threads that existed before the run plus the sampler itself
+        let observed_peak = peak.load(Ordering::Relaxed);
+        let peak_workers = observed_peak.saturating_sub(baseline_threads + 1);
+
+        dict.set_item("requested_threads", thread_count)?;
+        dict.set_item("baseline_threads", baseline_threads)?;
+        dict.set_item("observed_peak_threads", observed_peak)?;
+        dict.set_item("peak_concurrent_workers", peak_workers)?;
+        dict.set_item("concurrency_gap", thread_count.saturating_sub(peak_workers))?;
+
+        Ok(dict.into())
+    })
+}
+
+/// Run the arena test with `allocs_per_thread` allocations per worker, releasing
+/// the GIL so another thread can call request_arena_abort() to stop it early
+#[pyfunction]
+fn run_arena_test_abortable(py: Python<'_>, thread_count: usize, allocs_per_thread: usize) -> PyResult<PyObject> {
+    let start = Instant::now();
+    let done = py.allow_threads(|| task_with_allocs(thread_count, allocs_per_thread));
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("thread_count", thread_count)?;
+    dict.set_item("expected_allocations", thread_count * allocs_per_thread)?;
+    dict.set_item("allocations_done", done)?;
+    dict.set_item("aborted", abort_token().load(Ordering::Relaxed))?;
+    dict.set_item("duration_secs", start.elapsed().as_secs_f64())?;
+    Ok(dict.into())
+}
+
+/// Ask running arena workers to stop before their next allocation
+#[pyfunction]
+fn request_arena_abort() {
+    abort_token().store(true, Ordering::Relaxed);
+}
+
+// Block size for run_arena_under_python_threads; under glibc's default 128 KiB
+// mmap threshold so blocks come from malloc arenas rather than dedicated mappings
+const PY_THREAD_ALLOC_SIZE: usize = 64 * 1024;
+
+/// True when running on a free-threaded (Py_GIL_DISABLED) build with the GIL disabled
+#[pyfunction]
+fn is_free_threaded(py: Python<'_>) -> PyResult<bool> {
+    let sysconfig = py.import("sysconfig")?;
+    let gil_disabled_build = sysconfig
+        .call_method1("get_config_var", ("Py_GIL_DISABLED",))?
+        .extract::<Option<i64>>()?
+        == Some(1);
+    if !gil_disabled_build {
+        return Ok(false);
+    }
+    // A free-threaded build can still re-enable the GIL at runtime (PYTHON_GIL=1)
+    let sys = py.import("sys")?;
+    if !sys.hasattr("_is_gil_enabled")? {
+        return Ok(true);
+    }
+    Ok(!sys.getattr("_is_gil_enabled")?.call0()?.extract::<bool>()?)
+}
+
+// malloc a block and touch every byte so it counts towards RSS
+fn allocate_and_fill(size: usize) -> *mut libc::c_void {
+    let ptr = unsafe { libc::malloc(size) };
+    if !ptr.is_null() {
+        unsafe { std::ptr::write_bytes(ptr as *mut u8, 1, size) };
+    }
+    ptr
+}
+
+// Body of each Python thread: hold `allocs` blocks at once, then free them all
+#[pyfunction]
+fn python_thread_allocations(allocs: usize) {
+    let blocks: Vec<*mut libc::c_void> = (0..allocs).map(|_| allocate_and_fill(PY_THREAD_ALLOC_SIZE)).collect();
+    for block in blocks {
+        unsafe { libc::free(block) };
+    }
+}
+
+/// Run the arena allocation pattern from `py_thread_count` Python threads
+/// With the GIL the threads allocate one at a time; on a free-threaded build
+/// they run concurrently and each tends to get its own malloc arena
+#[pyfunction]
+fn run_arena_under_python_threads(py: Python<'_>, py_thread_count: usize, allocs_each: usize) -> PyResult<PyObject> {
+    let free_threaded = is_free_threaded(py)?;
+    let threading = py.import("threading")?;
+    let worker = wrap_pyfunction!(python_thread_allocations, py)?;
+
+    let initial_rss = rss_kib();
+    let stop = Arc::new(AtomicBool::new(false));
+    let peak = Arc::new(AtomicUsize::new(initial_rss as usize));
+    let sampler = {
+        let stop = Arc::clone(&stop);
+        let peak = Arc::clone(&peak);
+        thread::spawn(move || {
+            while !stop.load(Ordering::Relaxed) {
+                peak.fetch_max(rss_kib() as usize, Ordering::Relaxed);
+                thread::sleep(Duration::from_millis(1));
+            }
+        })
+    };
+
+    let kwargs = pyo3::types::PyDict::new(py);
+    kwargs.set_item("target", &worker)?;
+    kwargs.set_item("args", (allocs_each,))?;
+    let threads = (0..py_thread_count)
+        .map(|_| threading.getattr("Thread")?.call((), Some(&kwargs)))
+        .collect::<PyResult<Vec<_>>>()?;
+    for t in &threads {
+        t.call_method0("start")?;
+    }
+    for t in &threads {
+        t.call_method0("join")?;
+    }
+
+    stop.store(true, Ordering::Relaxed);
+    sampler.join().unwrap();
+    let final_rss = rss_kib();
+    let peak_rss = peak.load(Ordering::Relaxed).max(final_rss as usize);
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("free_threaded", free_threaded)?;
+    dict.set_item("py_thread_count", py_thread_count)?;
+    dict.set_item("allocs_each", allocs_each)?;
+    dict.set_item("alloc_size_bytes", PY_THREAD_ALLOC_SIZE)?;
+    dict.set_item("initial_rss_mib", initial_rss as f64 / 1024.0)?;
+    dict.set_item("peak_rss_mib", peak_rss as f64 / 1024.0)?;
+    dict.set_item("final_rss_mib", final_rss as f64 / 1024.0)?;
+    dict.set_item("retained_mib", final_rss.saturating_sub(initial_rss) as f64 / 1024.0)?;
+    Ok(dict.into())
+}
+
+// Size of the touched block each thread allocates in measure_stack_vs_heap_rss;
+// under glibc's default 128 KiB mmap threshold so it lands in a malloc arena
+const STACK_VS_HEAP_BLOCK_SIZE: usize = 64 * 1024;
+
+// Bind the calling thread's future allocations (and threads it spawns) to one
+// NUMA node; `None` restores the default policy. Uses the raw syscall so no
+// libnuma link is needed.
+#[cfg(all(target_os = "linux", feature = "numa"))]
+fn set_numa_policy(node: Option<usize>) -> std::io::Result<()> {
+    const MASK_BITS: usize = 1024;
+    let mut mask = [0 as libc::c_ulong; MASK_BITS / libc::c_ulong::BITS as usize];
+    let mode = match node {
+        Some(node) => {
+            mask[node / libc::c_ulong::BITS as usize] |= 1 << (node % libc::c_ulong::BITS as usize);
+            libc::MPOL_BIND
+        }
+        None => libc::MPOL_DEFAULT,
+    };
+    let (mask_ptr, max_node) = match node {
+        Some(_) => (mask.as_ptr(), MASK_BITS + 1),
+        None => (std::ptr::null(), 0),
+    };
+    if unsafe { libc::syscall(libc::SYS_set_mempolicy, mode, mask_ptr, max_node) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Run the arena task with memory bound to NUMA `node` (Linux, `numa` feature)
+/// Without NUMA support the task still runs and `binding_succeeded` is False
+#[pyfunction]
+fn run_arena_numa_bound(py: Python<'_>, node: usize, thread_count: usize) -> PyResult<PyObject> {
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("node", node)?;
+    dict.set_item("thread_count", thread_count)?;
+    dict.set_item("numa_supported", cfg!(all(target_os = "linux", feature = "numa")))?;
+
+    #[cfg(all(target_os = "linux", feature = "numa"))]
+    let bound = {
+        let node_dir = format!("/sys/devices/system/node/node{}", node);
+        if node >= 1024 || !std::path::Path::new(&node_dir).exists() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!("NUMA node {} does not exist", node)));
+        }
+        match set_numa_policy(Some(node)) {
+            Ok(()) => true,
+            Err(e) => {
+                dict.set_item("binding_error", e.to_string())?;
+                false
+            }
+        }
+    };
+    #[cfg(not(all(target_os = "linux", feature = "numa")))]
+    let bound = false;
+
+    let initial_rss = rss_kib() as f64 / 1024.0;
+    task(thread_count);
+    let final_rss = rss_kib() as f64 / 1024.0;
+
+    #[cfg(all(target_os = "linux", feature = "numa"))]
+    if bound {
+        set_numa_policy(None)?;
+    }
+
+    dict.set_item("binding_succeeded", bound)?;
+    dict.set_item("initial_rss_mib", initial_rss)?;
+    dict.set_item("final_rss_mib", final_rss)?;
+    Ok(dict.into())
+}
+
+/// Split RSS growth from spawning `thread_count` workers into the part caused by
+/// the parked threads themselves (stacks) and the part caused by one touched
+/// allocation per thread (heap arenas)
+#[pyfunction]
+fn measure_stack_vs_heap_rss(py: Python<'_>, thread_count: usize) -> PyResult<PyObject> {
+    use std::sync::Barrier;
+
+    let (baseline, after_spawn, after_alloc) = py.allow_threads(|| {
+        // Phases: all parked -> allocate -> all holding a block -> exit
+        let barrier = Arc::new(Barrier::new(thread_count + 1));
+        let baseline = rss_kib();
+
+        let handles: Vec<_> = (0..thread_count)
+            .map(|_| {
+                let barrier = Arc::clone(&barrier);
+                thread::spawn(move || {
+                    barrier.wait();
+                    barrier.wait();
+                    let block = std::hint::black_box(vec![1u8; STACK_VS_HEAP_BLOCK_SIZE]);
+                    barrier.wait();
+                    barrier.wait();
+                    drop(block);
+                })
+            })
+            .collect();
+
+        barrier.wait();
+        let after_spawn = rss_kib();
+        barrier.wait();
+        barrier.wait();
+        let after_alloc = rss_kib();
+        barrier.wait();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        (baseline, after_spawn, after_alloc)
+    });
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("thread_count", thread_count)?;
+    dict.set_item("block_size_bytes", STACK_VS_HEAP_BLOCK_SIZE)?;
+    dict.set_item("baseline_rss_mib", baseline as f64 / 1024.0)?;
+    dict.set_item("stack_delta_mib", after_spawn.saturating_sub(baseline) as f64 / 1024.0)?;
+    dict.set_item("heap_delta_mib", after_alloc.saturating_sub(after_spawn) as f64 / 1024.0)?;
+    Ok(dict.into())
+}
+
+// Producer/consumer block size: under the mmap threshold so blocks come from
+// the producers' arenas and are freed into them from other threads
+const HANDOFF_BLOCK_SIZE: usize = 64 * 1024;
+// Blocks in flight before producers have to wait for consumers
+const HANDOFF_QUEUE_CAPACITY: usize = 1024;
+
+/// Producers allocate touched blocks and push them through a bounded channel to
+/// consumers that free them, for `duration_secs`; every block is freed by a
+/// different thread than the one that allocated it
+#[pyfunction]
+fn benchmark_alloc_free_contention(
+    py: Python<'_>,
+    alloc_threads: usize,
+    free_threads: usize,
+    duration_secs: f64,
+) -> PyResult<PyObject> {
+    use std::sync::{Mutex, mpsc};
+
+    let alloc_threads = alloc_threads.max(1);
+    let free_threads = free_threads.max(1);
+    let duration = Duration::try_from_secs_f64(duration_secs)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid duration_secs: {}", e)))?;
+
+    let initial_rss = rss_kib() as f64 / 1024.0;
+    let (allocations, frees, elapsed) = py.allow_threads(|| {
+        let (sender, receiver) = mpsc::sync_channel::<Vec<u8>>(HANDOFF_QUEUE_CAPACITY);
+        let receiver = Arc::new(Mutex::new(receiver));
+        let start = Instant::now();
+
+        let producers: Vec<_> = (0..alloc_threads)
+            .map(|_| {
+                let sender = sender.clone();
+                thread::spawn(move || {
+                    let mut allocations = 0usize;
+                    while start.elapsed() < duration {
+                        if sender.send(vec![1u8; HANDOFF_BLOCK_SIZE]).is_err() {
+                            break;
+                        }
+                        allocations += 1;
+                    }
+                    allocations
+                })
+            })
+            .collect();
+        // Consumers see the channel close once every producer has dropped its sender
+        drop(sender);
+
+        let consumers: Vec<_> = (0..free_threads)
+            .map(|_| {
+                let receiver = Arc::clone(&receiver);
+                thread::spawn(move || {
+                    let mut frees = 0usize;
+                    loop {
+                        let block = lock_recovering(&receiver, "block channel").recv();
+                        match block {
+                            Ok(block) => {
+                                drop(block);
+                                frees += 1;
+                            }
+                            Err(_) => break,
+                        }
+                    }
+                    frees
+                })
+            })
+            .collect();
+
+        let allocations: usize = producers.into_iter().map(|h| h.join().unwrap()).sum();
+        let frees: usize = consumers.into_iter().map(|h| h.join().unwrap()).sum();
+        (allocations, frees, start.elapsed().as_secs_f64())
+    });
+    let final_rss = rss_kib() as f64 / 1024.0;
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("alloc_threads", alloc_threads)?;
+    dict.set_item("free_threads", free_threads)?;
+    dict.set_item("block_size_bytes", HANDOFF_BLOCK_SIZE)?;
+    dict.set_item("elapsed_secs", elapsed)?;
+    dict.set_item("allocations", allocations)?;
+    dict.set_item("frees", frees)?;
+    dict.set_item("allocs_per_sec", allocations as f64 / elapsed)?;
+    dict.set_item("frees_per_sec", frees as f64 / elapsed)?;
+    dict.set_item("initial_rss_mib", initial_rss)?;
+    dict.set_item("final_rss_mib", final_rss)?;
+    dict.set_item("retained_mib", final_rss - initial_rss)?;
+    Ok(dict.into())
+}
+
 /// Configure the number of allocations per thread (default: 1)
 #[pyfunction]
-fn set_allocs_per_thread(_allocs: usize) -> PyResult<()> {
-    // Note: This would require making ALLOCS_PER_THREAD mutable
-    // For now, just return Ok to show the interface
-    println!("Note: allocs_per_thread is currently fixed at {}", ALLOCS_PER_THREAD);
+fn set_allocs_per_thread(allocs: usize) -> PyResult<()> {
+    update_config(|config| config.allocs_per_thread = allocs);
+    Ok(())
+}
+
+/// Replace every arena tunable with the named load profile (see list_profiles)
+#[pyfunction]
+fn apply_profile(name: &str) -> PyResult<()> {
+    let profile = profiles::profile(name).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "Unknown profile '{}'; expected one of {:?}",
+            name,
+            profiles::PROFILE_NAMES
+        ))
+    })?;
+    update_config(|config| *config = profile);
+    Ok(())
+}
+
+/// Names accepted by apply_profile
+#[pyfunction]
+fn list_profiles() -> Vec<&'static str> {
+    profiles::PROFILE_NAMES.to_vec()
+}
+
+// Live configuration as get_config reports it
+fn set_config_items(dict: &Bound<'_, pyo3::types::PyDict>) -> PyResult<()> {
+    let config = config();
+    dict.set_item("profile", config.profile)?;
+    dict.set_item("thread_count", config.thread_count)?;
+    dict.set_item("allocs_per_thread", config.allocs_per_thread)?;
+    dict.set_item("alloc_size_bytes", config.alloc_size)?;
+    dict.set_item("alloc_size_mib", config.alloc_size as f64 / (1024.0 * 1024.0))?;
+    dict.set_item("pattern_length", config.pattern.len())?;
     Ok(())
 }
 
@@ -79,9 +495,7 @@ fn set_allocs_per_thread(_allocs: usize) -> PyResult<()> {
 fn get_config() -> PyResult<PyObject> {
     Python::with_gil(|py| {
         let dict = pyo3::types::PyDict::new(py);
-        dict.set_item("allocs_per_thread", ALLOCS_PER_THREAD)?;
-        dict.set_item("alloc_size_bytes", ALLOC_SIZE)?;
-        dict.set_item("alloc_size_mib", ALLOC_SIZE as f64 / (1024.0 * 1024.0))?;
+        set_config_items(&dict)?;
         Ok(dict.into())
     })
 }
@@ -117,6 +531,149 @@ fn get_memory_stats() -> PyResult<PyObject> {
     })
 }
 
+// /proc/self/status prefixes and the keys they map to in the memory stats
+const PROC_STATUS_FIELDS: [(&str, &str); 11] = [
+    ("VmRSS:", "vm_rss"),
+    ("VmPeak:", "vm_peak"),
+    ("VmSize:", "vm_size"),
+    ("VmHWM:", "vm_hwm"),
+    ("VmData:", "vm_data"),
+    ("VmStk:", "vm_stk"),
+    ("VmExe:", "vm_exe"),
+    ("VmLib:", "vm_lib"),
+    ("RssAnon:", "rss_anon"),
+    ("RssFile:", "rss_file"),
+    ("RssShmem:", "rss_shmem"),
+];
+
+/// Report which Vm*/Rss* fields /proc/self/status actually provided
+/// Lets callers tell a missing field (reported as 0 by get_memory_stats) from a real 0
+#[pyfunction]
+fn parse_proc_status_present() -> PyResult<PyObject> {
+    Python::with_gil(|py| {
+        let s = fs::read_to_string("/proc/self/status").unwrap_or_default();
+        let dict = pyo3::types::PyDict::new(py);
+
+        for (prefix, key) in PROC_STATUS_FIELDS {
+            let present = s.lines().any(|line| line.starts_with(prefix));
+            dict.set_item(key, present)?;
+        }
+
+        Ok(dict.into())
+    })
+}
+
+/// The kB fields of get_memory_stats as a pyclass, for comparing return shapes
+#[pyclass(frozen, get_all)]
+struct MemoryStatsRecord {
+    vm_rss_kb: u64,
+    vm_peak_kb: u64,
+    vm_size_kb: u64,
+    vm_hwm_kb: u64,
+    vm_data_kb: u64,
+    vm_stk_kb: u64,
+    vm_exe_kb: u64,
+    vm_lib_kb: u64,
+}
+
+/// Per-call cost of returning the get_memory_stats kB fields as a dict, a
+/// pyclass, or a plain tuple (stats are read once so only construction is timed)
+#[pyfunction]
+fn benchmark_return_shape(py: Python<'_>, iterations: usize) -> PyResult<PyObject> {
+    let iterations = iterations.max(1);
+    let stats = parse_proc_status();
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("vm_rss_kb", stats.vm_rss_kb)?;
+        dict.set_item("vm_peak_kb", stats.vm_peak_kb)?;
+        dict.set_item("vm_size_kb", stats.vm_size_kb)?;
+        dict.set_item("vm_hwm_kb", stats.vm_hwm_kb)?;
+        dict.set_item("vm_data_kb", stats.vm_data_kb)?;
+        dict.set_item("vm_stk_kb", stats.vm_stk_kb)?;
+        dict.set_item("vm_exe_kb", stats.vm_exe_kb)?;
+        dict.set_item("vm_lib_kb", stats.vm_lib_kb)?;
+        std::hint::black_box(dict);
+    }
+    let dict_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let record = Bound::new(py, MemoryStatsRecord {
+            vm_rss_kb: stats.vm_rss_kb,
+            vm_peak_kb: stats.vm_peak_kb,
+            vm_size_kb: stats.vm_size_kb,
+            vm_hwm_kb: stats.vm_hwm_kb,
+            vm_data_kb: stats.vm_data_kb,
+            vm_stk_kb: stats.vm_stk_kb,
+            vm_exe_kb: stats.vm_exe_kb,
+            vm_lib_kb: stats.vm_lib_kb,
+        })?;
+        std::hint::black_box(record);
+    }
+    let pyclass_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let tuple = (
+            stats.vm_rss_kb,
+            stats.vm_peak_kb,
+            stats.vm_size_kb,
+            stats.vm_hwm_kb,
+            stats.vm_data_kb,
+            stats.vm_stk_kb,
+            stats.vm_exe_kb,
+            stats.vm_lib_kb,
+        )
+            .into_pyobject(py)?;
+        std::hint::black_box(tuple);
+    }
+    let tuple_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("iterations", iterations)?;
+    dict.set_item("dict_ns", dict_ns)?;
+    dict.set_item("pyclass_ns", pyclass_ns)?;
+    dict.set_item("tuple_ns", tuple_ns)?;
+    Ok(dict.into())
+}
+
+/// The active profile's allocation size; equals the ALLOC_SIZE_BYTES module
+/// constant under the default profile
+#[pyfunction]
+fn alloc_size_bytes() -> usize {
+    config_alloc_size()
+}
+
+/// Per-access cost of `module.ALLOC_SIZE_BYTES` vs `module.alloc_size_bytes()`,
+/// both looked up on the module each time as an uncached hot loop would
+#[pyfunction]
+#[pyo3(pass_module)]
+fn benchmark_constant_access(m: &Bound<'_, PyModule>, iterations: usize) -> PyResult<PyObject> {
+    let py = m.py();
+    let iterations = iterations.max(1);
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        std::hint::black_box(m.getattr("ALLOC_SIZE_BYTES")?);
+    }
+    let constant_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        std::hint::black_box(m.getattr("alloc_size_bytes")?.call0()?);
+    }
+    let function_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("iterations", iterations)?;
+    dict.set_item("constant_ns", constant_ns)?;
+    dict.set_item("function_ns", function_ns)?;
+    dict.set_item("function_over_constant", function_ns / constant_ns)?;
+    Ok(dict.into())
+}
+
 /// Get system and process statistics
 #[pyfunction]
 fn get_system_stats() -> PyResult<PyObject> {
@@ -191,9 +748,7 @@ fn get_all_stats() -> PyResult<PyObject> {
         
         // Config
         let config_dict = pyo3::types::PyDict::new(py);
-        config_dict.set_item("allocs_per_thread", ALLOCS_PER_THREAD)?;
-        config_dict.set_item("alloc_size_bytes", ALLOC_SIZE)?;
-        config_dict.set_item("alloc_size_mib", ALLOC_SIZE as f64 / (1024.0 * 1024.0))?;
+        set_config_items(&config_dict)?;
         dict.set_item("config", config_dict)?;
         
         // Timestamp
@@ -234,6 +789,437 @@ fn monitor_memory(duration_seconds: f64, interval_seconds: f64) -> PyResult<PyOb
     })
 }
 
+/// Copy glibc allocator counters (summed over all arenas) into `dict`
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+fn set_mallinfo_items(dict: &Bound<'_, pyo3::types::PyDict>) -> PyResult<()> {
+    let info = unsafe { libc::mallinfo2() };
+    dict.set_item("arena", info.arena)?;
+    dict.set_item("ordblks", info.ordblks)?;
+    dict.set_item("hblks", info.hblks)?;
+    dict.set_item("hblkhd", info.hblkhd)?;
+    dict.set_item("uordblks", info.uordblks)?;
+    dict.set_item("fordblks", info.fordblks)?;
+    dict.set_item("keepcost", info.keepcost)?;
+    Ok(())
+}
+
+/// Get glibc malloc statistics via mallinfo2()
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+#[pyfunction]
+fn get_mallinfo() -> PyResult<PyObject> {
+    Python::with_gil(|py| {
+        let dict = pyo3::types::PyDict::new(py);
+        set_mallinfo_items(&dict)?;
+        Ok(dict.into())
+    })
+}
+
+/// Guess where glibc served a single `size`-byte malloc from, using mallinfo2
+/// deltas: "mmap" if hblkhd grew, "arena" if arena-owned in-use bytes changed,
+/// otherwise "tcache" (tcache/fastbin chunks already count as in use)
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+#[pyfunction]
+fn classify_allocation(size: usize) -> PyResult<PyObject> {
+    Python::with_gil(|py| {
+        let before = unsafe { libc::mallinfo2() };
+        // black_box keeps LLVM from eliding the malloc/free pair
+        let ptr = std::hint::black_box(unsafe { libc::malloc(size) });
+        let after = unsafe { libc::mallinfo2() };
+        if ptr.is_null() {
+            return Err(pyo3::exceptions::PyMemoryError::new_err(format!("malloc({}) failed", size)));
+        }
+        unsafe { libc::free(ptr) };
+
+        let delta = |a: usize, b: usize| b as i64 - a as i64;
+        let hblkhd_delta = delta(before.hblkhd, after.hblkhd);
+        let arena_delta = delta(before.arena, after.arena);
+        let uordblks_delta = delta(before.uordblks, after.uordblks);
+
+        let classification = if hblkhd_delta > 0 {
+            "mmap"
+        } else if arena_delta != 0 || uordblks_delta != 0 {
+            "arena"
+        } else {
+            "tcache"
+        };
+
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("size", size)?;
+        dict.set_item("classification", classification)?;
+        dict.set_item("arena_delta", arena_delta)?;
+        dict.set_item("uordblks_delta", uordblks_delta)?;
+        dict.set_item("hblkhd_delta", hblkhd_delta)?;
+        Ok(dict.into())
+    })
+}
+
+/// Replay a scripted allocation pattern to produce realistic fragmentation
+/// Each `(size, keep)` entry allocates `size` touched bytes; the block stays
+/// alive until the end of the run if `keep` is true, otherwise it is freed
+/// immediately. Reports RSS and mallinfo2 counters while kept blocks are live.
+/// Without `pattern`, the active profile's pattern is replayed.
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+#[pyfunction]
+#[pyo3(signature = (pattern=None))]
+fn run_fragmentation_pattern(pattern: Option<Vec<(usize, bool)>>) -> PyResult<PyObject> {
+    let pattern = match pattern {
+        Some(pattern) => pattern,
+        None => config().pattern,
+    };
+    if pattern.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "No pattern given and the active profile has none (try apply_profile(\"fragmentation\"))",
+        ));
+    }
+
+    Python::with_gil(|py| {
+        let dict = pyo3::types::PyDict::new(py);
+        let initial_rss = rss_kib() as f64 / 1024.0;
+
+        let (kept, kept_bytes, freed_bytes) = replay_pattern(&pattern);
+
+        dict.set_item("allocations", pattern.len())?;
+        dict.set_item("kept_blocks", kept.len())?;
+        dict.set_item("kept_bytes", kept_bytes)?;
+        dict.set_item("freed_bytes", freed_bytes)?;
+        dict.set_item("initial_rss_mib", initial_rss)?;
+        dict.set_item("final_rss_mib", rss_kib() as f64 / 1024.0)?;
+        set_mallinfo_items(&dict)?;
+
+        drop(kept);
+        dict.set_item("after_release_rss_mib", rss_kib() as f64 / 1024.0)?;
+
+        Ok(dict.into())
+    })
+}
+
+/// Allocate `count` touched blocks of `block_mib`, free them all, and report
+/// how much of the growth RSS kept afterwards (no malloc_trim in between)
+#[pyfunction]
+fn demonstrate_retention(block_mib: usize, count: usize) -> PyResult<PyObject> {
+    Python::with_gil(|py| {
+        let dict = pyo3::types::PyDict::new(py);
+        let block_size = block_mib
+            .checked_mul(1024 * 1024)
+            .filter(|size| size.checked_mul(count).is_some())
+            .ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "{} blocks of {} MiB overflow the address space",
+                    count, block_mib
+                ))
+            })?;
+
+        let baseline_rss = rss_kib() as f64 / 1024.0;
+        let blocks: Vec<Vec<u8>> = (0..count).map(|_| vec![1u8; block_size]).collect();
+        let peak_rss = rss_kib() as f64 / 1024.0;
+        drop(blocks);
+        let after_free_rss = rss_kib() as f64 / 1024.0;
+
+        let growth = peak_rss - baseline_rss;
+        let retained = (after_free_rss - baseline_rss).clamp(0.0, growth.max(0.0));
+        let retention_percent = if growth > 0.0 { retained / growth * 100.0 } else { 0.0 };
+
+        dict.set_item("block_mib", block_mib)?;
+        dict.set_item("count", count)?;
+        dict.set_item("baseline_rss_mib", baseline_rss)?;
+        dict.set_item("peak_rss_mib", peak_rss)?;
+        dict.set_item("after_free_rss_mib", after_free_rss)?;
+        dict.set_item("retained_mib", retained)?;
+        dict.set_item("retention_percent", retention_percent)?;
+
+        Ok(dict.into())
+    })
+}
+
+/// Tell the kernel [addr, addr + size) is no longer needed with
+/// madvise(MADV_DONTNEED); `addr` must be page-aligned
+/// Returns whether madvise succeeded
+#[cfg(target_os = "linux")]
+#[pyfunction]
+fn advise_dontneed(addr: usize, size: usize) -> PyResult<bool> {
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+    if !addr.is_multiple_of(page_size) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "addr {:#x} is not aligned to the {}-byte page size",
+            addr, page_size
+        )));
+    }
+    let rc = unsafe { libc::madvise(addr as *mut libc::c_void, size, libc::MADV_DONTNEED) };
+    Ok(rc == 0)
+}
+
+/// Allocate and touch a page-aligned `block_mib` block, madvise(MADV_DONTNEED)
+/// it while still allocated, and report how much RSS that gave back
+#[cfg(target_os = "linux")]
+#[pyfunction]
+fn demonstrate_madvise_reclaim(block_mib: usize) -> PyResult<PyObject> {
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+    let block_size = block_mib
+        .checked_mul(1024 * 1024)
+        .ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!("{} MiB overflows the address space", block_mib))
+        })?
+        .max(page_size);
+    let layout = std::alloc::Layout::from_size_align(block_size, page_size)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+
+    let baseline_rss = rss_kib() as f64 / 1024.0;
+    let ptr = unsafe { std::alloc::alloc(layout) };
+    if ptr.is_null() {
+        return Err(pyo3::exceptions::PyMemoryError::new_err(format!("Failed to allocate {} MiB", block_mib)));
+    }
+    unsafe { std::ptr::write_bytes(ptr, 1, block_size) };
+    let touched_rss = rss_kib() as f64 / 1024.0;
+    let advised = advise_dontneed(ptr as usize, block_size);
+    let after_advise_rss = rss_kib() as f64 / 1024.0;
+    unsafe { std::alloc::dealloc(ptr, layout) };
+    let advised = advised?;
+
+    Python::with_gil(|py| {
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("block_mib", block_mib)?;
+        dict.set_item("advise_succeeded", advised)?;
+        dict.set_item("baseline_rss_mib", baseline_rss)?;
+        dict.set_item("touched_rss_mib", touched_rss)?;
+        dict.set_item("after_advise_rss_mib", after_advise_rss)?;
+        dict.set_item("reclaimed_mib", touched_rss - after_advise_rss)?;
+        Ok(dict.into())
+    })
+}
+
+/// Read (rss, shared, private) in kB from /proc/self/statm into a stack
+/// buffer, parsing without allocating so a forked child can call it
+#[cfg(unix)]
+fn statm_kib(page_kib: u64) -> Option<(u64, u64, u64)> {
+    let mut buf = [0u8; 128];
+    let fd = unsafe { libc::open(c"/proc/self/statm".as_ptr(), libc::O_RDONLY | libc::O_CLOEXEC) };
+    if fd < 0 {
+        return None;
+    }
+    let n = unsafe { libc::read(fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len()) };
+    unsafe { libc::close(fd) };
+    if n <= 0 {
+        return None;
+    }
+
+    // Fields are pages: size resident shared text lib data dt
+    let mut fields = [0u64; 3];
+    let mut idx = 0;
+    let mut in_number = false;
+    for &b in &buf[..n as usize] {
+        if b.is_ascii_digit() {
+            fields[idx] = fields[idx].saturating_mul(10).saturating_add((b - b'0') as u64);
+            in_number = true;
+        } else if in_number {
+            in_number = false;
+            idx += 1;
+            if idx == fields.len() {
+                break;
+            }
+        }
+    }
+    if idx < fields.len() {
+        return None;
+    }
+
+    let resident = fields[1] * page_kib;
+    let shared = fields[2] * page_kib;
+    Some((resident, shared, resident.saturating_sub(shared)))
+}
+
+/// Compare RSS of this process with a freshly forked child (copy-on-write)
+/// The child only reads /proc/self/statm without allocating, reports back over
+/// a pipe, and exits with _exit(); shared is statm's file-backed/shmem count
+#[cfg(unix)]
+#[pyfunction]
+fn measure_fork_rss() -> PyResult<PyObject> {
+    use std::io::Read;
+    use std::os::fd::FromRawFd;
+
+    const REPORT_LEN: usize = 3 * std::mem::size_of::<u64>();
+
+    Python::with_gil(|py| {
+        let dict = pyo3::types::PyDict::new(py);
+        let page_kib = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as u64 / 1024;
+
+        let mut fds = [0 as libc::c_int; 2];
+        if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        let (read_fd, write_fd) = (fds[0], fds[1]);
+
+        let (parent_rss, parent_shared, parent_private) = statm_kib(page_kib).unwrap_or((0, 0, 0));
+
+        let pid = unsafe { libc::fork() };
+        if pid < 0 {
+            let err = std::io::Error::last_os_error();
+            unsafe {
+                libc::close(read_fd);
+                libc::close(write_fd);
+            }
+            return Err(err.into());
+        }
+
+        if pid == 0 {
+            // Child: async-signal-safe calls only
+            unsafe {
+                libc::close(read_fd);
+                let Some((rss, shared, private)) = statm_kib(page_kib) else { libc::_exit(1) };
+                let report = [rss, shared, private];
+                let written = libc::write(write_fd, report.as_ptr() as *const libc::c_void, REPORT_LEN);
+                libc::_exit(if written == REPORT_LEN as isize { 0 } else { 2 });
+            }
+        }
+
+        // Parent
+        unsafe { libc::close(write_fd) };
+        let mut report = Vec::with_capacity(REPORT_LEN);
+        let read_result = unsafe { fs::File::from_raw_fd(read_fd) }.read_to_end(&mut report);
+
+        let mut status: libc::c_int = 0;
+        unsafe { libc::waitpid(pid, &mut status, 0) };
+        read_result?;
+
+        if report.len() != REPORT_LEN {
+            return Err(pyo3::exceptions::PyRuntimeError::new_err(format!(
+                "Child sent {} of {} report bytes (exit status {})",
+                report.len(),
+                REPORT_LEN,
+                libc::WEXITSTATUS(status)
+            )));
+        }
+        let child: Vec<u64> = report
+            .chunks_exact(std::mem::size_of::<u64>())
+            .map(|c| u64::from_ne_bytes(c.try_into().unwrap()))
+            .collect();
+
+        dict.set_item("parent_pid", std::process::id())?;
+        dict.set_item("child_pid", pid)?;
+        dict.set_item("child_exit_status", libc::WEXITSTATUS(status))?;
+        dict.set_item("parent_rss_kb", parent_rss)?;
+        dict.set_item("parent_shared_kb", parent_shared)?;
+        dict.set_item("parent_private_kb", parent_private)?;
+        dict.set_item("child_rss_kb", child[0])?;
+        dict.set_item("child_shared_kb", child[1])?;
+        dict.set_item("child_private_kb", child[2])?;
+
+        Ok(dict.into())
+    })
+}
+
+// Bump when keys in the run_and_report JSON are renamed or removed
+const REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Run the arena test once and write a JSON summary of the run to `path`:
+/// timing, RSS retained, /proc/self/status, mallinfo2 (glibc only) and config
+/// Returns the path written
+#[pyfunction]
+fn run_and_report(py: Python<'_>, thread_count: usize, path: &str) -> PyResult<String> {
+    let result = py.allow_threads(|| run_arena_test_with_timing(thread_count));
+
+    let report = pyo3::types::PyDict::new(py);
+    report.set_item("schema_version", REPORT_SCHEMA_VERSION)?;
+    report.set_item("pid", std::process::id())?;
+    report.set_item("thread_count", result.thread_count)?;
+    report.set_item("duration_secs", result.duration_secs)?;
+    report.set_item("initial_rss_mib", result.initial_rss_mib)?;
+    report.set_item("final_rss_mib", result.final_rss_mib)?;
+    report.set_item("retained_mib", result.difference_mib)?;
+    report.set_item("memory_stats", get_memory_stats()?)?;
+    report.set_item("config", get_config()?)?;
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    report.set_item("mallinfo", get_mallinfo()?)?;
+    #[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+    report.set_item("mallinfo", py.None())?;
+
+    let kwargs = pyo3::types::PyDict::new(py);
+    kwargs.set_item("indent", 2)?;
+    let json: String = py.import("json")?.call_method("dumps", (report,), Some(&kwargs))?.extract()?;
+    fs::write(path, json + "\n")
+        .map_err(|e| pyo3::exceptions::PyOSError::new_err(format!("Failed to write {}: {}", path, e)))?;
+    Ok(path.to_string())
+}
+
+/// Write backtraces of large allocations recorded since the last export to
+/// `path` as folded stacks (`frame;frame;frame bytes`) for inferno/flamegraph
+/// Returns the number of distinct stacks written
+#[cfg(feature = "alloc-profile")]
+#[pyfunction]
+fn export_alloc_profile(py: Python<'_>, path: &str) -> PyResult<usize> {
+    py.allow_threads(|| alloc_profile::export(std::path::Path::new(path)))
+        .map_err(|e| pyo3::exceptions::PyOSError::new_err(format!("Failed to write {}: {}", path, e)))
+}
+
+/// Count resident pages of [addr, addr + size) with mincore()
+/// The range is widened to page boundaries; unmapped ranges raise OSError
+#[cfg(unix)]
+#[pyfunction]
+fn page_residency(addr: usize, size: usize) -> PyResult<PyObject> {
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+    let start = addr & !(page_size - 1);
+    let end = addr
+        .checked_add(size)
+        .and_then(|end| end.checked_next_multiple_of(page_size))
+        .ok_or_else(|| pyo3::exceptions::PyOverflowError::new_err("addr + size overflows"))?;
+    let total_pages = (end - start) / page_size;
+
+    let mut vec = vec![0u8; total_pages];
+    let rc = unsafe { libc::mincore(start as *mut libc::c_void, end - start, vec.as_mut_ptr() as *mut _) };
+    if rc != 0 {
+        return Err(std::io::Error::last_os_error().into());
+    }
+    let resident_pages = vec.iter().filter(|&&page| page & 1 != 0).count();
+
+    Python::with_gil(|py| {
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("page_size", page_size)?;
+        dict.set_item("start", start)?;
+        dict.set_item("total_pages", total_pages)?;
+        dict.set_item("resident_pages", resident_pages)?;
+        Ok(dict.into())
+    })
+}
+
+/// Start a background thread pushing newline-delimited JSON memory snapshots
+/// to every client connected to the Unix domain socket at `socket_path`
+#[cfg(unix)]
+#[pyfunction]
+#[pyo3(signature = (socket_path, interval_seconds=1.0))]
+fn start_stats_server(socket_path: &str, interval_seconds: f64) -> PyResult<()> {
+    if !interval_seconds.is_finite() || interval_seconds <= 0.0 {
+        return Err(pyo3::exceptions::PyValueError::new_err("interval_seconds must be positive"));
+    }
+
+    let mut server = lock_recovering(&stats_server::STATS_SERVER, "stats server");
+    if server.is_some() {
+        return Err(pyo3::exceptions::PyRuntimeError::new_err("Stats server is already running"));
+    }
+
+    let started = stats_server::StatsServer::start(
+        std::path::Path::new(socket_path),
+        Duration::from_secs_f64(interval_seconds),
+    )
+    .map_err(|e| pyo3::exceptions::PyOSError::new_err(format!("Failed to start stats server on {}: {}", socket_path, e)))?;
+    *server = Some(started);
+    Ok(())
+}
+
+/// Stop the stats server and remove its socket
+/// Returns False if no server was running
+#[cfg(unix)]
+#[pyfunction]
+fn stop_stats_server(py: Python<'_>) -> PyResult<bool> {
+    let server = lock_recovering(&stats_server::STATS_SERVER, "stats server").take();
+
+    match server {
+        Some(server) => {
+            py.allow_threads(|| server.stop());
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
 /// A Python module implemented in Rust for glibc arena testing
 #[pymodule]
 fn glibc_arena_poc(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -242,19 +1228,57 @@ fn glibc_arena_poc(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(get_rss_kib, m)?)?;
     m.add_function(wrap_pyfunction!(run_arena_test, m)?)?;
     m.add_function(wrap_pyfunction!(run_arena_test_detailed, m)?)?;
+    m.add_function(wrap_pyfunction!(run_arena_test_thread_peak, m)?)?;
+    m.add_function(wrap_pyfunction!(run_arena_test_abortable, m)?)?;
+    m.add_function(wrap_pyfunction!(request_arena_abort, m)?)?;
+    m.add_function(wrap_pyfunction!(measure_stack_vs_heap_rss, m)?)?;
+    m.add_function(wrap_pyfunction!(benchmark_alloc_free_contention, m)?)?;
+    m.add_function(wrap_pyfunction!(is_free_threaded, m)?)?;
+    m.add_function(wrap_pyfunction!(run_arena_under_python_threads, m)?)?;
+    m.add_function(wrap_pyfunction!(run_arena_numa_bound, m)?)?;
+    m.add_function(wrap_pyfunction!(demonstrate_retention, m)?)?;
+    #[cfg(target_os = "linux")]
+    m.add_function(wrap_pyfunction!(advise_dontneed, m)?)?;
+    #[cfg(target_os = "linux")]
+    m.add_function(wrap_pyfunction!(demonstrate_madvise_reclaim, m)?)?;
     m.add_function(wrap_pyfunction!(set_allocs_per_thread, m)?)?;
     m.add_function(wrap_pyfunction!(get_config, m)?)?;
+    m.add_function(wrap_pyfunction!(apply_profile, m)?)?;
+    m.add_function(wrap_pyfunction!(list_profiles, m)?)?;
     
     // Statistics and monitoring functions
     m.add_function(wrap_pyfunction!(get_memory_stats, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_proc_status_present, m)?)?;
+    m.add_function(wrap_pyfunction!(benchmark_return_shape, m)?)?;
+    m.add_function(wrap_pyfunction!(alloc_size_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(benchmark_constant_access, m)?)?;
+    m.add_class::<MemoryStatsRecord>()?;
     m.add_function(wrap_pyfunction!(get_system_stats, m)?)?;
     m.add_function(wrap_pyfunction!(get_all_stats, m)?)?;
     m.add_function(wrap_pyfunction!(monitor_memory, m)?)?;
+    m.add_function(wrap_pyfunction!(run_and_report, m)?)?;
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    m.add_function(wrap_pyfunction!(get_mallinfo, m)?)?;
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    m.add_function(wrap_pyfunction!(run_fragmentation_pattern, m)?)?;
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    m.add_function(wrap_pyfunction!(classify_allocation, m)?)?;
+    #[cfg(unix)]
+    m.add_function(wrap_pyfunction!(measure_fork_rss, m)?)?;
+    #[cfg(unix)]
+    m.add_function(wrap_pyfunction!(page_residency, m)?)?;
+    #[cfg(feature = "alloc-profile")]
+    m.add_function(wrap_pyfunction!(export_alloc_profile, m)?)?;
+    #[cfg(unix)]
+    m.add_function(wrap_pyfunction!(start_stats_server, m)?)?;
+    #[cfg(unix)]
+    m.add_function(wrap_pyfunction!(stop_stats_server, m)?)?;
     
     // Module-level constants
     m.add("DEFAULT_THREAD_COUNT", DEFAULT_THREAD_COUNT)?;
     m.add("ALLOC_SIZE_BYTES", ALLOC_SIZE)?;
     m.add("ALLOC_SIZE_MIB", ALLOC_SIZE as f64 / (1024.0 * 1024.0))?;
+    m.add("NUMA_SUPPORT", cfg!(all(target_os = "linux", feature = "numa")))?;
     
     Ok(())
 }
@@ -4,23 +4,38 @@ use std::io::{self, Read};
 mod core;
 use core::*;
 
+mod profiles;
+
+// Usage: glibc_arena_poc [thread_count|-] [profile]
 fn main() {
     let args: Vec<String> = env::args().collect();
+
+    if let Some(name) = args.get(2) {
+        match profiles::profile(name) {
+            Some(profile) => update_config(|config| *config = profile),
+            None => {
+                eprintln!("Unknown profile '{}'; expected one of {:?}", name, profiles::PROFILE_NAMES);
+                std::process::exit(2);
+            }
+        }
+    }
+    let config = config();
     
     let thread_count = if args.len() > 1 {
         args[1].parse::<usize>().unwrap_or_else(|_| {
-            DEFAULT_THREAD_COUNT
+            config.thread_count
         })
     } else {
-        DEFAULT_THREAD_COUNT
+        config.thread_count
     };
 
     println!("=== Glibc Arena Memory Leak Test ===");
     let pid = std::process::id();
     println!("PID: {}", pid);
+    println!("Profile: {}", config.profile);
     println!("Thread count: {}", thread_count);
-    println!("Allocations per thread: {}", ALLOCS_PER_THREAD);
-    println!("Allocation size: {:.1} MiB ({} bytes)", ALLOC_SIZE as f64 / (1024.0 * 1024.0), ALLOC_SIZE);
+    println!("Allocations per thread: {}", config.allocs_per_thread);
+    println!("Allocation size: {:.1} MiB ({} bytes)", config.alloc_size as f64 / (1024.0 * 1024.0), config.alloc_size);
     
     println!("\nMonitoring commands:");
     println!("  watch -n0.5 pmap -x {}", pid);
@@ -47,6 +62,18 @@ fn main() {
     }
 
     println!("\nAllocations done. Memory may still appear >0 due to per-thread arenas.");
+
+    // Kept blocks stay alive through the observation window below
+    let (kept, kept_bytes, freed_bytes) = replay_pattern(&config.pattern);
+    if !config.pattern.is_empty() {
+        println!(
+            "Replayed {}-step pattern: {} blocks kept ({:.2} MiB), {:.2} MiB freed",
+            config.pattern.len(),
+            kept.len(),
+            kept_bytes as f64 / (1024.0 * 1024.0),
+            freed_bytes as f64 / (1024.0 * 1024.0)
+        );
+    }
     
     // Show additional stats
     let mem_stats = parse_proc_status();
@@ -55,6 +82,7 @@ fn main() {
     println!("VmPeak (peak): {:.2} MiB", mem_stats.vm_peak_mib());
     println!("VmSize (virtual): {:.2} MiB", mem_stats.vm_size_kb as f64 / 1024.0);
     println!("VmData (data): {:.2} MiB", mem_stats.vm_data_kb as f64 / 1024.0);
+    println!("Threads: {}", get_thread_count());
 
     println!("\nSleeping for 5 minutes so you can watch memory usage...");
     thread::sleep(Duration::from_secs(300));
@@ -64,4 +92,5 @@ fn main() {
     println!("\n=== Final Memory Stats ===");
     println!("Final RSS: {:.2} MiB", final_stats.vm_rss_mib());
     println!("Peak RSS: {:.2} MiB", final_stats.vm_peak_mib());
+    drop(kept);
 }
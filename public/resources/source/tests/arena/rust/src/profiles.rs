@@ -0,0 +1,48 @@
+// Named load profiles that fix every arena tunable, so runs are comparable
+use crate::core::{ArenaConfig, ALLOC_SIZE};
+
+pub const PROFILE_NAMES: [&str; 4] = ["default", "small", "fragmentation", "stress"];
+
+// Upper bound on stress-profile threads so a big machine can't spawn itself to death
+pub const STRESS_THREAD_CAP: usize = 1024;
+
+const MIB: usize = 1024 * 1024;
+
+/// The configuration for profile `name`, or None if there is no such profile
+pub fn profile(name: &str) -> Option<ArenaConfig> {
+    let config = match name {
+        "default" => ArenaConfig::default(),
+        "small" => ArenaConfig {
+            profile: "small",
+            thread_count: 8,
+            allocs_per_thread: 1,
+            alloc_size: MIB,
+            pattern: Vec::new(),
+        },
+        "fragmentation" => ArenaConfig {
+            profile: "fragmentation",
+            thread_count: 8,
+            allocs_per_thread: 1,
+            alloc_size: MIB,
+            pattern: fragmentation_pattern(),
+        },
+        "stress" => {
+            let cores = std::thread::available_parallelism().map(|p| p.get()).unwrap_or(1);
+            ArenaConfig {
+                profile: "stress",
+                thread_count: (cores * 64).min(STRESS_THREAD_CAP),
+                allocs_per_thread: 4,
+                alloc_size: ALLOC_SIZE,
+                pattern: Vec::new(),
+            }
+        }
+        _ => return None,
+    };
+    Some(config)
+}
+
+// Kept 4 KiB blocks pinning freed 16 KiB / 64 KiB holes between them; all sizes
+// stay under the mmap threshold so they come from the arena
+fn fragmentation_pattern() -> Vec<(usize, bool)> {
+    [(4096, true), (16 * 1024, false), (64 * 1024, false)].repeat(256)
+}
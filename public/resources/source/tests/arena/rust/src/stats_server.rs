@@ -0,0 +1,110 @@
+// Push-based memory statistics over a Unix domain socket
+use std::{
+    io::{ErrorKind, Write},
+    os::unix::{
+        fs::FileTypeExt,
+        net::{UnixListener, UnixStream},
+    },
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use crate::core::*;
+
+const POLL_SLICE: Duration = Duration::from_millis(20);
+const CLIENT_WRITE_TIMEOUT: Duration = Duration::from_millis(200);
+
+pub struct StatsServer {
+    path: PathBuf,
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+}
+
+pub static STATS_SERVER: Mutex<Option<StatsServer>> = Mutex::new(None);
+
+/// Format the current memory snapshot as a single JSON line
+pub fn snapshot_json() -> String {
+    let stats = parse_proc_status();
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64();
+    format!(
+        "{{\"timestamp\":{:.6},\"pid\":{},\"thread_count\":{},\"vm_rss_kb\":{},\"vm_peak_kb\":{},\"vm_size_kb\":{},\"vm_hwm_kb\":{},\"vm_data_kb\":{}}}\n",
+        timestamp,
+        std::process::id(),
+        get_thread_count(),
+        stats.vm_rss_kb,
+        stats.vm_peak_kb,
+        stats.vm_size_kb,
+        stats.vm_hwm_kb,
+        stats.vm_data_kb,
+    )
+}
+
+impl StatsServer {
+    pub fn start(path: &Path, interval: Duration) -> std::io::Result<Self> {
+        // Only clear out a stale socket, never an arbitrary file
+        if let Ok(meta) = std::fs::symlink_metadata(path)
+            && meta.file_type().is_socket()
+        {
+            std::fs::remove_file(path)?;
+        }
+
+        let listener = UnixListener::bind(path)?;
+        listener.set_nonblocking(true)?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = Arc::clone(&stop);
+        let handle = thread::Builder::new()
+            .name("arena-stats-server".to_string())
+            .spawn(move || serve(listener, interval, stop_flag))?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            stop,
+            handle,
+        })
+    }
+
+    pub fn stop(self) {
+        self.stop.store(true, Ordering::SeqCst);
+        let _ = self.handle.join();
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+fn serve(listener: UnixListener, interval: Duration, stop: Arc<AtomicBool>) {
+    let mut clients: Vec<UnixStream> = Vec::new();
+
+    while !stop.load(Ordering::SeqCst) {
+        // Pick up any clients that connected since the last tick
+        loop {
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    if stream.set_nonblocking(false).is_ok()
+                        && stream.set_write_timeout(Some(CLIENT_WRITE_TIMEOUT)).is_ok()
+                    {
+                        clients.push(stream);
+                    }
+                }
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(_) => break,
+            }
+        }
+
+        // Disconnected or stalled clients are dropped instead of killing the thread
+        let line = snapshot_json();
+        clients.retain_mut(|client| client.write_all(line.as_bytes()).is_ok());
+
+        let tick = Instant::now();
+        while tick.elapsed() < interval && !stop.load(Ordering::SeqCst) {
+            thread::sleep(POLL_SLICE.min(interval));
+        }
+    }
+}
@@ -0,0 +1,302 @@
+# tests/arena/rust/test_glibc_arena_poc.py
+# Tests for the glibc arena PoC extension module
+
+import unittest
+import importlib.util
+import json
+import os
+import platform
+import socket
+import sys
+import sysconfig
+import tempfile
+import threading
+import time
+from pathlib import Path
+
+class TestGlibcArenaPoc(unittest.TestCase):
+    """Test suite for the glibc_arena_poc extension module"""
+
+    @classmethod
+    def setUpClass(cls):
+        """Load the built glibc_arena_poc extension"""
+        base_dir = Path(__file__).parent
+        lib_ext = ".dylib" if platform.system().lower() == "darwin" else ".so"
+        lib_paths = [
+            base_dir / "target" / "release" / f"libglibc_arena_poc{lib_ext}",
+            base_dir / "target" / "debug" / f"libglibc_arena_poc{lib_ext}",
+        ]
+
+        cls.module = None
+        for lib_path in lib_paths:
+            if lib_path.exists():
+                try:
+                    spec = importlib.util.spec_from_file_location("glibc_arena_poc", lib_path)
+                    module = importlib.util.module_from_spec(spec)
+                    spec.loader.exec_module(module)
+                    cls.module = module
+                    break
+                except ImportError as e:
+                    print(f"Failed to load {lib_path}: {e}")
+                    continue
+
+        if cls.module is None:
+            raise unittest.SkipTest("glibc_arena_poc not found. Run 'cargo build --release' in arena/rust/")
+
+    @unittest.skipUnless(hasattr(socket, "AF_UNIX"), "Unix domain sockets not available")
+    def test_stats_server_streams_snapshots(self):
+        """Test that the stats server pushes JSON snapshots to a connected client"""
+        with tempfile.TemporaryDirectory() as tmp_dir:
+            socket_path = os.path.join(tmp_dir, "arena_stats.sock")
+            self.module.start_stats_server(socket_path, 0.05)
+            try:
+                with socket.socket(socket.AF_UNIX, socket.SOCK_STREAM) as client:
+                    client.settimeout(5.0)
+                    client.connect(socket_path)
+                    reader = client.makefile("r")
+                    snapshots = [json.loads(reader.readline()) for _ in range(2)]
+                    reader.close()
+
+                for snapshot in snapshots:
+                    self.assertEqual(snapshot["pid"], os.getpid())
+                    self.assertGreater(snapshot["vm_rss_kb"], 0, "Snapshot should report RSS")
+                self.assertLessEqual(snapshots[0]["timestamp"], snapshots[1]["timestamp"])
+            finally:
+                self.assertTrue(self.module.stop_stats_server(), "Server should have been running")
+
+            self.assertFalse(os.path.exists(socket_path), "Socket should be removed on stop")
+            self.assertFalse(self.module.stop_stats_server(), "Second stop should be a no-op")
+
+    @unittest.skipUnless(platform.system() == "Linux", "/proc/self/status is Linux-only")
+    def test_proc_status_present(self):
+        """Test that VmRSS is reported as present on Linux"""
+        present = self.module.parse_proc_status_present()
+
+        self.assertTrue(present["vm_rss"], "VmRSS should be present in /proc/self/status")
+        self.assertTrue(all(isinstance(v, bool) for v in present.values()))
+
+    def test_return_shape_benchmark(self):
+        """Test dict vs pyclass vs tuple construction timings"""
+        result = self.module.benchmark_return_shape(1000)
+
+        for key in ("dict_ns", "pyclass_ns", "tuple_ns"):
+            self.assertGreater(result[key], 0, f"{key} should be positive")
+
+    def test_run_and_report(self):
+        """Test that a run writes a parseable JSON report"""
+        with tempfile.TemporaryDirectory() as tmp_dir:
+            path = os.path.join(tmp_dir, "arena_report.json")
+            written = self.module.run_and_report(2, path)
+            with open(written) as f:
+                report = json.load(f)
+
+        self.assertEqual(written, path)
+        self.assertEqual(report["thread_count"], 2)
+        self.assertIn("retained_mib", report)
+        self.assertIsInstance(report["schema_version"], int)
+
+    def test_constant_access_benchmark(self):
+        """Test module constant vs function call access timings"""
+        self.assertEqual(self.module.alloc_size_bytes(), self.module.ALLOC_SIZE_BYTES)
+        result = self.module.benchmark_constant_access(1000)
+
+        self.assertGreater(result["constant_ns"], 0, "Constant access should take time")
+        self.assertGreater(result["function_ns"], 0, "Function call should take time")
+
+    def test_apply_profile(self):
+        """Test that applying a load profile updates the live config"""
+        self.assertIn("small", self.module.list_profiles())
+        try:
+            self.module.apply_profile("small")
+            config = self.module.get_config()
+
+            self.assertEqual(config["profile"], "small")
+            self.assertEqual(config["thread_count"], 8)
+            self.assertEqual(config["alloc_size_bytes"], 1024 * 1024)
+            self.assertEqual(self.module.alloc_size_bytes(), 1024 * 1024)
+            self.assertEqual(self.module.run_arena_test_detailed()["thread_count"], 8)
+        finally:
+            self.module.apply_profile("default")
+
+        with self.assertRaises(ValueError):
+            self.module.apply_profile("no-such-profile")
+        self.assertEqual(self.module.get_config()["profile"], "default")
+
+    def test_fragmentation_pattern(self):
+        """Test that an interleaved keep/free pattern leaves both used and free heap bytes"""
+        if not hasattr(self.module, "run_fragmentation_pattern"):
+            self.skipTest("mallinfo2 requires glibc")
+
+        # Kept 4 KiB blocks pin the freed 16 KiB holes between them
+        pattern = [(4096, True), (16384, False)] * 64
+        result = self.module.run_fragmentation_pattern(pattern)
+
+        self.assertEqual(result["allocations"], len(pattern))
+        self.assertEqual(result["kept_blocks"], 64)
+        self.assertGreater(result["uordblks"], 0, "Kept blocks should count as used bytes")
+        self.assertGreater(result["fordblks"], 0, "Freed holes should count as free bytes")
+        self.assertGreater(result["final_rss_mib"], 0)
+
+    def test_classify_allocation(self):
+        """Test that a tiny and a large allocation are classified differently"""
+        if not hasattr(self.module, "classify_allocation"):
+            self.skipTest("mallinfo2 requires glibc")
+
+        tiny = self.module.classify_allocation(16)
+        large = self.module.classify_allocation(64 * 1024 * 1024)
+
+        self.assertIn(tiny["classification"], ("tcache", "arena"))
+        self.assertEqual(large["classification"], "mmap")
+        self.assertNotEqual(tiny["classification"], large["classification"])
+
+    def test_thread_peak_observed(self):
+        """Test that sampling during task() observes concurrently alive threads"""
+        requested = 32
+        # Warm-up: after the first freed 64 MiB block glibc raises its dynamic mmap
+        # threshold, so the measured run's workers don't serialize on mmap/munmap
+        self.module.run_arena_test_thread_peak(requested, 1000)
+        # Enough allocations per worker that they outlive the 1 ms sampling period
+        result = self.module.run_arena_test_thread_peak(requested, 100_000)
+
+        self.assertEqual(result["requested_threads"], requested)
+        self.assertGreater(result["peak_concurrent_workers"], 1, "Multi-thread run should overlap workers")
+        self.assertGreaterEqual(result["peak_concurrent_workers"], requested // 4,
+                                "Most requested workers should be alive at once")
+        self.assertLessEqual(result["peak_concurrent_workers"], requested)
+
+    def test_arena_abort(self):
+        """Test that request_arena_abort stops a long-running task early"""
+        expected = 2 * 10_000_000
+        results = []
+        runner = threading.Thread(
+            target=lambda: results.append(self.module.run_arena_test_abortable(2, 10_000_000))
+        )
+        runner.start()
+        time.sleep(0.1)
+        self.module.request_arena_abort()
+        runner.join(timeout=10)
+
+        self.assertFalse(runner.is_alive(), "Aborted task should finish quickly")
+        result = results[0]
+        self.assertTrue(result["aborted"])
+        self.assertEqual(result["expected_allocations"], expected)
+        self.assertLess(result["allocations_done"], expected, "Abort should cut allocations short")
+
+    def test_abort_does_not_leak_into_next_run(self):
+        """Test that a stale abort request doesn't empty a later non-abortable run"""
+        self.module.request_arena_abort()
+        result = self.module.run_arena_test_detailed(2)
+
+        self.assertEqual(result["allocations"], 2 * result["allocs_per_thread"],
+                         "Every worker should allocate after an earlier abort")
+
+    def test_alloc_free_contention(self):
+        """Test cross-thread allocate/free throughput through a bounded queue"""
+        result = self.module.benchmark_alloc_free_contention(2, 2, 0.2)
+
+        self.assertGreater(result["allocs_per_sec"], 0, "Allocation throughput should be reported")
+        self.assertGreater(result["frees_per_sec"], 0, "Free throughput should be reported")
+        self.assertEqual(result["allocations"], result["frees"], "Every queued block should be freed")
+        self.assertIn("retained_mib", result)
+
+    def test_arena_under_python_threads(self):
+        """Test arena allocation from Python threads reports RSS figures"""
+        result = self.module.run_arena_under_python_threads(2, 8)
+
+        gil_disabled = (sysconfig.get_config_var("Py_GIL_DISABLED") == 1
+                        and not (hasattr(sys, "_is_gil_enabled") and sys._is_gil_enabled()))
+        self.assertEqual(result["free_threaded"], gil_disabled)
+        self.assertEqual(result["py_thread_count"], 2)
+        self.assertGreater(result["peak_rss_mib"], 0)
+        self.assertGreaterEqual(result["retained_mib"], 0)
+
+    def test_arena_numa_bound(self):
+        """Test that NUMA-bound runs report whether binding succeeded"""
+        if not self.module.NUMA_SUPPORT:
+            self.skipTest("Built without the numa feature")
+
+        result = self.module.run_arena_numa_bound(0, 4)
+
+        self.assertTrue(result["numa_supported"])
+        self.assertIsInstance(result["binding_succeeded"], bool)
+        self.assertGreater(result["final_rss_mib"], 0)
+
+    def test_export_alloc_profile(self):
+        """Test that large allocations are exported as folded stacks"""
+        if not hasattr(self.module, "export_alloc_profile"):
+            self.skipTest("Built without the alloc-profile feature")
+
+        self.module.run_arena_test(2)
+        with tempfile.TemporaryDirectory() as tmp_dir:
+            path = os.path.join(tmp_dir, "arena.folded")
+            stacks = self.module.export_alloc_profile(path)
+            with open(path) as f:
+                lines = [line.rstrip("\n") for line in f if line.strip()]
+
+        self.assertGreater(stacks, 0)
+        self.assertGreaterEqual(len(lines), 1, "Profile should contain at least one folded stack")
+        stack, _, size = lines[0].rpartition(" ")
+        self.assertTrue(stack, "Folded line should start with a stack")
+        self.assertGreater(int(size), 0, "Folded line should end with a byte count")
+
+    def test_stack_vs_heap_rss(self):
+        """Test that stack- and heap-attributable RSS deltas are both reported"""
+        result = self.module.measure_stack_vs_heap_rss(16)
+
+        self.assertEqual(result["thread_count"], 16)
+        self.assertGreaterEqual(result["stack_delta_mib"], 0.0)
+        self.assertGreaterEqual(result["heap_delta_mib"], 0.0)
+
+    def test_retention_percentage(self):
+        """Test that the retention demo reports a percentage within bounds"""
+        result = self.module.demonstrate_retention(1, 16)
+
+        self.assertGreater(result["peak_rss_mib"], result["baseline_rss_mib"], "Touched blocks should grow RSS")
+        self.assertGreaterEqual(result["retention_percent"], 0.0)
+        self.assertLessEqual(result["retention_percent"], 100.0)
+        self.assertGreaterEqual(result["retained_mib"], 0.0)
+
+    @unittest.skipUnless(platform.system() == "Linux", "MADV_DONTNEED reclaim is Linux-specific")
+    def test_madvise_reclaim(self):
+        """Test that MADV_DONTNEED on a touched block lowers RSS"""
+        result = self.module.demonstrate_madvise_reclaim(32)
+
+        self.assertTrue(result["advise_succeeded"])
+        self.assertLess(result["after_advise_rss_mib"], result["touched_rss_mib"], "RSS should drop after advise")
+
+        with self.assertRaises(ValueError):
+            self.module.advise_dontneed(1, 4096)
+
+    @unittest.skipUnless(platform.system() == "Linux", "mincore residency semantics are Linux-specific")
+    def test_page_residency_half_touched(self):
+        """Test that touching half of a fresh mapping leaves about half its pages resident"""
+        import ctypes
+        import mmap
+
+        page = mmap.PAGESIZE
+        pages = 64
+        region = mmap.mmap(-1, pages * page)
+        try:
+            for i in range(0, pages, 2):
+                region[i * page] = 1
+            addr = ctypes.addressof(ctypes.c_char.from_buffer(region))
+            result = self.module.page_residency(addr, pages * page)
+        finally:
+            region.close()
+
+        self.assertEqual(result["total_pages"], pages)
+        self.assertAlmostEqual(result["resident_pages"], pages // 2, delta=pages // 8)
+
+    @unittest.skipUnless(hasattr(os, "fork"), "fork() not available")
+    def test_fork_rss(self):
+        """Test that parent and forked child both report RSS"""
+        result = self.module.measure_fork_rss()
+
+        self.assertEqual(result["parent_pid"], os.getpid())
+        self.assertNotEqual(result["child_pid"], os.getpid())
+        self.assertEqual(result["child_exit_status"], 0)
+        self.assertGreater(result["parent_rss_kb"], 0, "Parent RSS should be positive")
+        self.assertGreater(result["child_rss_kb"], 0, "Child RSS should be positive")
+
+if __name__ == '__main__':
+    unittest.main(verbosity=2)
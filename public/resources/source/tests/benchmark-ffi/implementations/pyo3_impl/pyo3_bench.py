@@ -29,6 +29,8 @@ class PyO3Benchmark:
     def __init__(self):
         """Initialize the PyO3 library wrapper."""
         self.lib = benchlib_pyo3
+        # Refuse to benchmark against a benchlib with a different struct layout
+        self.lib.check_benchlib_abi()
         
     # =============================================================================
     # Benchmark Functions (matching ctypes exactly)
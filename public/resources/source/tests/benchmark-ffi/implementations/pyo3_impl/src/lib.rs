@@ -6,10 +6,14 @@
  */
 
 use pyo3::prelude::*;
+use std::alloc::Layout;
+use std::collections::{BTreeMap, HashMap};
 use std::os::raw::{c_char, c_int};
+use std::sync::{LazyLock, Mutex, MutexGuard, OnceLock};
 
 // Link to the original C library functions
 extern "C" {
+    fn c_to_c_baseline(iterations: c_int) -> c_int;
     fn noop();
     fn return_int() -> c_int;
     fn return_int64() -> i64;
@@ -55,8 +59,18 @@ extern "C" {
     fn c_transform(x: c_int) -> c_int;
     fn apply_callback(x: c_int, transform: extern "C" fn(c_int) -> c_int) -> c_int;
     fn sum_with_transform(arr: *const i32, n: usize, transform: extern "C" fn(i32) -> i32) -> i32;
+    
+    // ABI versioning
+    fn benchlib_abi_version() -> c_int;
 }
 
+// Exception hierarchy for benchlib errors
+pyo3::create_exception!(benchlib_pyo3, BenchlibError, pyo3::exceptions::PyException);
+pyo3::create_exception!(benchlib_pyo3, DimensionError, BenchlibError);
+
+// ABI version these bindings were written against (BENCHLIB_ABI_VERSION in benchlib.c)
+const BENCHLIB_ABI_VERSION: i32 = 1;
+
 // C struct definition to match benchlib.h
 #[repr(C)]
 struct SimpleStructC {
@@ -83,6 +97,33 @@ impl SimpleStruct {
     fn new() -> Self {
         SimpleStruct { x: 0, y: 0, value: 0.0 }
     }
+
+    /// Serialize to the 16-byte little-endian layout of the C struct (i32, i32, f64)
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(std::mem::size_of::<SimpleStructC>());
+        data.extend_from_slice(&self.x.to_le_bytes());
+        data.extend_from_slice(&self.y.to_le_bytes());
+        data.extend_from_slice(&self.value.to_le_bytes());
+        data
+    }
+
+    /// Parse the 16-byte little-endian layout produced by `to_bytes`
+    #[classmethod]
+    fn from_bytes(_cls: &Bound<'_, pyo3::types::PyType>, data: &[u8]) -> PyResult<SimpleStruct> {
+        let expected = std::mem::size_of::<SimpleStructC>();
+        if data.len() != expected {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "SimpleStruct requires exactly {} bytes, got {}",
+                expected,
+                data.len()
+            )));
+        }
+        Ok(SimpleStruct {
+            x: i32::from_le_bytes(data[0..4].try_into().unwrap()),
+            y: i32::from_le_bytes(data[4..8].try_into().unwrap()),
+            value: f64::from_le_bytes(data[8..16].try_into().unwrap()),
+        })
+    }
 }
 
 // Basic operations
@@ -91,6 +132,11 @@ fn py_noop() {
     unsafe { noop() }
 }
 
+#[pyfunction]
+fn py_c_to_c_baseline(iterations: i32) -> i32 {
+    unsafe { c_to_c_baseline(iterations) }
+}
+
 #[pyfunction]
 fn py_return_int() -> i32 {
     unsafe { return_int() }
@@ -133,6 +179,29 @@ fn py_logical_not(a: bool) -> bool {
     unsafe { logical_not(a) }
 }
 
+/// Run every (a, b) combination through C `logical_and`/`logical_or`
+///
+/// The extern declarations pass Rust `bool` straight through as C `_Bool`: both
+/// are one byte holding only 0 or 1, so no conversion happens at the boundary.
+/// `bool_size` reports `size_of::<bool>()` so a platform breaking that shows up.
+#[pyfunction]
+fn bool_abi_probe(py: Python<'_>) -> PyResult<PyObject> {
+    let truth_table = pyo3::types::PyList::empty(py);
+    for (a, b) in [(false, false), (false, true), (true, false), (true, true)] {
+        let row = pyo3::types::PyDict::new(py);
+        row.set_item("a", a)?;
+        row.set_item("b", b)?;
+        row.set_item("and", unsafe { logical_and(a, b) })?;
+        row.set_item("or", unsafe { logical_or(a, b) })?;
+        truth_table.append(row)?;
+    }
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("bool_size", std::mem::size_of::<bool>())?;
+    dict.set_item("truth_table", truth_table)?;
+    Ok(dict.into())
+}
+
 // Floating point operations
 #[pyfunction]
 fn py_add_float(a: f32, b: f32) -> f32 {
@@ -166,6 +235,12 @@ fn py_sum_int32_array(arr: Vec<i32>) -> i32 {
     unsafe { sum_int32_array(arr.as_ptr(), arr.len()) }
 }
 
+// Safe Rust sum widened to i64, so it can't wrap where the C i32 sum would
+#[pyfunction]
+fn py_sum_int32_array_i64(arr: Vec<i32>) -> i64 {
+    arr.iter().map(|&x| x as i64).sum()
+}
+
 #[pyfunction]
 fn py_fill_int32_array(mut arr: Vec<i32>, value: i32) -> Vec<i32> {
     unsafe { fill_int32_array(arr.as_mut_ptr(), arr.len(), value) };
@@ -191,6 +266,18 @@ fn py_string_identity(s: &str) -> String {
     result_c_str.to_string_lossy().into_owned()
 }
 
+/// Round-trip raw bytes through string_identity and decode the result with `encoding`
+#[pyfunction]
+fn py_string_identity_encoded(py: Python<'_>, s: &[u8], encoding: &str) -> PyResult<PyObject> {
+    let c_str = std::ffi::CString::new(s)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Input contains NUL byte: {}", e)))?;
+    let result_ptr = unsafe { string_identity(c_str.as_ptr()) };
+    let result_bytes = unsafe { std::ffi::CStr::from_ptr(result_ptr) }.to_bytes();
+    // Decode with Python's codec machinery; unknown encodings raise LookupError
+    let decoded = pyo3::types::PyBytes::new(py, result_bytes).call_method1("decode", (encoding,))?;
+    Ok(decoded.unbind())
+}
+
 #[pyfunction]
 fn py_string_concat(a: &[u8], b: &[u8]) -> String {
     let result_ptr = unsafe { 
@@ -292,6 +379,14 @@ fn py_vector_norm(v: Vec<f64>) -> f64 {
 }
 
 // Memory operations
+
+// Sizes of live allocate_sized_checked blocks, keyed by start address; ordered so an
+// interior pointer can be traced back to the block containing it
+static SIZED_ALLOCATIONS: LazyLock<Mutex<BTreeMap<usize, usize>>> = LazyLock::new(|| Mutex::new(BTreeMap::new()));
+
+// Layouts of live aligned allocations, keyed by address, needed to free them
+static ALIGNED_ALLOCATIONS: LazyLock<Mutex<HashMap<usize, Layout>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
 #[pyfunction]
 fn py_allocate_sized(size: usize) -> PyResult<usize> {
     let ptr = unsafe { allocate_sized(size) };
@@ -302,6 +397,8 @@ fn py_allocate_sized(size: usize) -> PyResult<usize> {
     }
 }
 
+// Unchecked like the ctypes/cffi/pybind11 bindings, since this is the benchmarked
+// path; use deallocate_checked for provenance validation
 #[pyfunction]
 fn py_deallocate(ptr_addr: usize) {
     if ptr_addr != 0 {
@@ -309,6 +406,148 @@ fn py_deallocate(ptr_addr: usize) {
     }
 }
 
+/// allocate_sized that records the block so deallocate_checked can validate it
+#[pyfunction]
+fn py_allocate_sized_checked(size: usize) -> PyResult<usize> {
+    let ptr = py_allocate_sized(size)?;
+    if ptr != 0 {
+        lock_recovering(&SIZED_ALLOCATIONS, "sized allocation").insert(ptr, size);
+    }
+    Ok(ptr)
+}
+
+/// Free a block from allocate_sized_checked; any other address, including one
+/// inside a live block, is rejected rather than handed to free()
+#[pyfunction]
+fn py_deallocate_checked(ptr_addr: usize) -> PyResult<()> {
+    if ptr_addr == 0 {
+        return Ok(());
+    }
+
+    let mut allocations = lock_recovering(&SIZED_ALLOCATIONS, "sized allocation");
+    match allocations.range(..=ptr_addr).next_back() {
+        Some((&start, _)) if start == ptr_addr => {
+            allocations.remove(&start);
+        }
+        Some((&start, &size)) if ptr_addr < start + size => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "address not from allocate_sized: {:#x} is {} bytes into the block at {:#x}",
+                ptr_addr,
+                ptr_addr - start,
+                start
+            )));
+        }
+        _ => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "address not from allocate_sized: {:#x}",
+                ptr_addr
+            )));
+        }
+    }
+    drop(allocations);
+
+    py_deallocate(ptr_addr);
+    Ok(())
+}
+
+// Lock global state, recovering the data if a previous holder panicked,
+// so one panic doesn't break every later call into the module
+fn lock_recovering<'a, T>(mutex: &'a Mutex<T>, name: &str) -> MutexGuard<'a, T> {
+    mutex.lock().unwrap_or_else(|poisoned| {
+        eprintln!("Warning: {} lock was poisoned by a panic; recovering its state", name);
+        mutex.clear_poison();
+        poisoned.into_inner()
+    })
+}
+
+// Test-only: poison ALIGNED_ALLOCATIONS by panicking while holding its lock
+#[cfg(feature = "test-hooks")]
+#[pyfunction]
+fn _poison_aligned_allocations() {
+    let _ = std::panic::catch_unwind(|| {
+        let _guard = ALIGNED_ALLOCATIONS.lock();
+        panic!("deliberately poisoning the aligned allocation tracker");
+    });
+}
+
+#[pyfunction]
+fn py_allocate_aligned(size: usize, align: usize) -> PyResult<usize> {
+    if size == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err("size must be non-zero"));
+    }
+    let layout = Layout::from_size_align(size, align).map_err(|_| {
+        pyo3::exceptions::PyValueError::new_err(format!("align must be a power of two, got {}", align))
+    })?;
+
+    let ptr = unsafe { std::alloc::alloc(layout) };
+    if ptr.is_null() {
+        return Err(pyo3::exceptions::PyMemoryError::new_err(format!(
+            "Failed to allocate {} bytes aligned to {}",
+            size, align
+        )));
+    }
+
+    let addr = ptr as usize;
+    lock_recovering(&ALIGNED_ALLOCATIONS, "aligned allocation").insert(addr, layout);
+    Ok(addr)
+}
+
+#[pyfunction]
+fn py_deallocate_aligned(ptr_addr: usize) -> PyResult<()> {
+    let layout = lock_recovering(&ALIGNED_ALLOCATIONS, "aligned allocation").remove(&ptr_addr).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!("{:#x} was not returned by allocate_aligned", ptr_addr))
+    })?;
+    unsafe { std::alloc::dealloc(ptr_addr as *mut u8, layout) };
+    Ok(())
+}
+
+// Alloc/free pairs per second through the tracker from `threads` threads at once
+fn run_tracker_ops(threads: usize, ops_each: usize) -> PyResult<f64> {
+    let start = std::time::Instant::now();
+    let results: Vec<PyResult<()>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(move || -> PyResult<()> {
+                    for _ in 0..ops_each {
+                        let addr = py_allocate_aligned(64, 64)?;
+                        py_deallocate_aligned(addr)?;
+                    }
+                    Ok(())
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| {
+                h.join()
+                    .map_err(|_| pyo3::exceptions::PyRuntimeError::new_err("tracker benchmark thread panicked"))
+                    .and_then(|result| result)
+            })
+            .collect()
+    });
+    results.into_iter().collect::<PyResult<Vec<()>>>()?;
+    Ok((threads * ops_each) as f64 / start.elapsed().as_secs_f64())
+}
+
+/// Throughput of allocate_aligned/deallocate_aligned pairs with `thread_count`
+/// threads sharing the tracker lock, against a single-thread baseline
+#[pyfunction]
+fn benchmark_tracker_contention(py: Python<'_>, thread_count: usize, ops_each: usize) -> PyResult<PyObject> {
+    let thread_count = thread_count.max(1);
+    let ops_each = ops_each.max(1);
+    let (single, contended) = py.allow_threads(|| -> PyResult<(f64, f64)> {
+        Ok((run_tracker_ops(1, ops_each)?, run_tracker_ops(thread_count, ops_each)?))
+    })?;
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("thread_count", thread_count)?;
+    dict.set_item("ops_each", ops_each)?;
+    dict.set_item("single_thread_ops_per_sec", single)?;
+    dict.set_item("ops_per_sec", contended)?;
+    dict.set_item("scaling", contended / single)?;
+    Ok(dict.into())
+}
+
 // Callback operations
 #[pyfunction]
 fn py_c_transform(x: i32) -> i32 {
@@ -335,11 +574,819 @@ fn py_sum_with_transform(arr: Vec<i32>, _size: usize, callback: PyObject) -> PyR
     })
 }
 
+// Named Python transforms, so a benchmark can look a callback up by name
+// instead of passing it on every call
+static TRANSFORMS: LazyLock<Mutex<HashMap<String, PyObject>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[pyfunction]
+fn register_transform(py: Python<'_>, name: String, callback: PyObject) -> PyResult<()> {
+    if !callback.bind(py).is_callable() {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!("transform '{}' is not callable", name)));
+    }
+    lock_recovering(&TRANSFORMS, "transform registry").insert(name, callback);
+    Ok(())
+}
+
+#[pyfunction]
+fn apply_transform(py: Python<'_>, name: &str, x: i32) -> PyResult<i32> {
+    let callback = lock_recovering(&TRANSFORMS, "transform registry")
+        .get(name)
+        .map(|callback| callback.clone_ref(py))
+        .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(name.to_string()))?;
+    // Lock released before calling back into Python, which may re-enter the registry
+    callback.call1(py, (x,))?.extract(py)
+}
+
+/// Registered transform names, sorted
+#[pyfunction]
+fn list_transforms() -> Vec<String> {
+    let mut names: Vec<String> = lock_recovering(&TRANSFORMS, "transform registry").keys().cloned().collect();
+    names.sort();
+    names
+}
+
+#[pyfunction]
+fn clear_transforms() {
+    // Take the callbacks out first so their destructors run without the lock held
+    let removed = std::mem::take(&mut *lock_recovering(&TRANSFORMS, "transform registry"));
+    drop(removed);
+}
+
+// Cold-start measurements
+//
+// Methodology: a fresh interpreter is spawned via `sys.executable`, loads this
+// extension from its own `__file__`, and times the very first `py_noop` and
+// `py_add_int32` calls with `time.perf_counter_ns()`. The child process has
+// never executed these code paths, so the figures include page faults, PLT
+// resolution into benchlib.so and cold i-cache/branch predictor state. A second
+// (warm) call is timed in the same child for reference.
+const COLD_CALL_SCRIPT: &str = r#"
+import importlib.util, sys, time
+spec = importlib.util.spec_from_file_location("benchlib_pyo3", sys.argv[1])
+mod = importlib.util.module_from_spec(spec)
+spec.loader.exec_module(mod)
+noop, add = mod.py_noop, mod.py_add_int32
+t0 = time.perf_counter_ns(); noop(); t1 = time.perf_counter_ns()
+add(1, 2); t2 = time.perf_counter_ns()
+noop(); t3 = time.perf_counter_ns()
+add(1, 2); t4 = time.perf_counter_ns()
+print(t1 - t0, t2 - t1, t3 - t2, t4 - t3)
+"#;
+
+/// Measure the first-call cost of `py_noop` / `py_add_int32` in a fresh subprocess
+#[pyfunction]
+#[pyo3(pass_module)]
+fn benchmark_cold_first_call(m: &Bound<'_, PyModule>) -> PyResult<PyObject> {
+    let py = m.py();
+    let module_path: String = m.getattr("__file__")?.extract()?;
+    let python: String = py.import("sys")?.getattr("executable")?.extract()?;
+
+    let output = std::process::Command::new(&python)
+        .args(["-c", COLD_CALL_SCRIPT, &module_path])
+        .output()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to spawn {}: {}", python, e)))?;
+    if !output.status.success() {
+        return Err(pyo3::exceptions::PyRuntimeError::new_err(format!(
+            "Cold-call subprocess failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    let timings: Vec<u64> = String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .filter_map(|t| t.parse().ok())
+        .collect();
+    if timings.len() != 4 {
+        return Err(pyo3::exceptions::PyRuntimeError::new_err(format!(
+            "Unexpected cold-call output: {:?}",
+            String::from_utf8_lossy(&output.stdout)
+        )));
+    }
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("method", "subprocess")?;
+    dict.set_item("noop_first_call_ns", timings[0])?;
+    dict.set_item("add_int32_first_call_ns", timings[1])?;
+    dict.set_item("noop_warm_call_ns", timings[2])?;
+    dict.set_item("add_int32_warm_call_ns", timings[3])?;
+    Ok(dict.into())
+}
+
+// GIL contention measurements
+
+/// Run `vector_norm` over `data` `iterations` times on each of `threads` native
+/// threads, either keeping the GIL for the whole loop or releasing it via
+/// `allow_threads`. Returns kernel calls per second across all threads.
+fn run_norm_kernel(data: &std::sync::Arc<Vec<f64>>, threads: usize, iterations: usize, release_gil: bool) -> PyResult<f64> {
+    let start = std::time::Instant::now();
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let data = std::sync::Arc::clone(data);
+            std::thread::spawn(move || {
+                Python::with_gil(|py| {
+                    let kernel = || {
+                        for _ in 0..iterations {
+                            std::hint::black_box(unsafe { vector_norm(data.as_ptr(), data.len()) });
+                        }
+                    };
+                    if release_gil {
+                        py.allow_threads(kernel);
+                    } else {
+                        kernel();
+                    }
+                });
+            })
+        })
+        .collect();
+    // Join every worker before reporting so none outlive the call
+    let joined: Vec<_> = handles.into_iter().map(|handle| handle.join()).collect();
+    if joined.iter().any(|result| result.is_err()) {
+        return Err(pyo3::exceptions::PyRuntimeError::new_err("norm kernel thread panicked"));
+    }
+    Ok((threads * iterations) as f64 / start.elapsed().as_secs_f64())
+}
+
+/// Compare throughput of the same `vector_norm` kernel with the GIL held vs released
+#[pyfunction]
+#[pyo3(signature = (n, iterations, threads=None))]
+fn benchmark_gil_vs_released(py: Python<'_>, n: usize, iterations: usize, threads: Option<usize>) -> PyResult<PyObject> {
+    let threads = threads
+        .unwrap_or_else(|| std::thread::available_parallelism().map(|p| p.get()).unwrap_or(2))
+        .max(1);
+    let data = std::sync::Arc::new((0..n).map(|i| i as f64 * 0.5).collect::<Vec<f64>>());
+
+    // Worker threads need to take the GIL, so the caller must let go of it
+    let (held, released) = py.allow_threads(|| -> PyResult<(f64, f64)> {
+        Ok((
+            run_norm_kernel(&data, threads, iterations, false)?,
+            run_norm_kernel(&data, threads, iterations, true)?,
+        ))
+    })?;
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("n", n)?;
+    dict.set_item("iterations", iterations)?;
+    dict.set_item("threads", threads)?;
+    dict.set_item("gil_held_ops_per_sec", held)?;
+    dict.set_item("gil_released_ops_per_sec", released)?;
+    dict.set_item("speedup", released / held)?;
+    Ok(dict.into())
+}
+
+/// Time producing an `n`-element numpy array vs a Python list from the same Rust `Vec`
+/// Both sides start from a fresh copy of the data so only the conversion differs
+#[pyfunction]
+fn benchmark_np_array_output(py: Python<'_>, n: usize, iterations: usize) -> PyResult<PyObject> {
+    use numpy::IntoPyArray;
+
+    let iterations = iterations.max(1);
+    let data: Vec<f64> = (0..n).map(|i| i as f64 * 0.5).collect();
+
+    let start = std::time::Instant::now();
+    for _ in 0..iterations {
+        let array = data.clone().into_pyarray(py);
+        std::hint::black_box(array);
+    }
+    let numpy_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+
+    let start = std::time::Instant::now();
+    for _ in 0..iterations {
+        let list = pyo3::types::PyList::new(py, data.clone())?;
+        std::hint::black_box(list);
+    }
+    let list_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("n", n)?;
+    dict.set_item("iterations", iterations)?;
+    dict.set_item("numpy_array_ns", numpy_ns)?;
+    dict.set_item("python_list_ns", list_ns)?;
+    dict.set_item("list_over_numpy", list_ns / numpy_ns)?;
+    Ok(dict.into())
+}
+
+/// Time `py_string_identity` through the Python call path for each string length
+/// Returns `{length: ns_per_call}` to show the linear copy cost of crossing
+#[pyfunction]
+#[pyo3(pass_module)]
+fn benchmark_string_size_scaling(m: &Bound<'_, PyModule>, sizes: Vec<usize>, iterations: usize) -> PyResult<PyObject> {
+    let py = m.py();
+    let identity = m.getattr("py_string_identity")?;
+    let iterations = iterations.max(1);
+
+    let dict = pyo3::types::PyDict::new(py);
+    for size in sizes {
+        let text = pyo3::types::PyString::new(py, &"x".repeat(size));
+        let start = std::time::Instant::now();
+        for _ in 0..iterations {
+            identity.call1((&text,))?;
+        }
+        dict.set_item(size, start.elapsed().as_nanos() as f64 / iterations as f64)?;
+    }
+    Ok(dict.into())
+}
+
+// Fill an `n`-element buffer in C and return it as a list / as a numpy array
+#[pyfunction]
+fn filled_int32_list(n: usize) -> Vec<i32> {
+    let mut arr = vec![0i32; n];
+    unsafe { fill_int32_array(arr.as_mut_ptr(), n, 7) };
+    arr
+}
+
+#[pyfunction]
+fn filled_int32_numpy(py: Python<'_>, n: usize) -> Bound<'_, numpy::PyArray1<i32>> {
+    use numpy::IntoPyArray;
+
+    let mut arr = vec![0i32; n];
+    unsafe { fill_int32_array(arr.as_mut_ptr(), n, 7) };
+    arr.into_pyarray(py)
+}
+
+/// Compare the Python-visible cost of returning `n` C-filled i32s as a list vs a numpy array
+#[pyfunction]
+fn benchmark_return_container(py: Python<'_>, n: usize, iterations: usize) -> PyResult<PyObject> {
+    let iterations = iterations.max(1);
+    let as_list = wrap_pyfunction!(filled_int32_list, py)?;
+    let as_numpy = wrap_pyfunction!(filled_int32_numpy, py)?;
+
+    let start = std::time::Instant::now();
+    for _ in 0..iterations {
+        as_list.call1((n,))?;
+    }
+    let list_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+
+    let start = std::time::Instant::now();
+    for _ in 0..iterations {
+        as_numpy.call1((n,))?;
+    }
+    let numpy_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("n", n)?;
+    dict.set_item("iterations", iterations)?;
+    dict.set_item("list_return_ns", list_ns)?;
+    dict.set_item("numpy_return_ns", numpy_ns)?;
+    dict.set_item("copy_out_tax_ns", list_ns - numpy_ns)?;
+    Ok(dict.into())
+}
+
+/// Time getting at `n` f64s passed in from Python: a numpy array borrowed via
+/// `PyReadonlyArray1::as_slice` (zero-copy) vs a list extracted into `Vec<f64>`
+/// Both sides sum the data so the borrowed slice is actually read
+#[pyfunction]
+fn benchmark_numpy_vs_list_extraction(py: Python<'_>, n: usize, iterations: usize) -> PyResult<PyObject> {
+    use numpy::{PyArray1, PyReadonlyArray1};
+
+    let iterations = iterations.max(1);
+    let data: Vec<f64> = (0..n).map(|i| i as f64 * 0.5).collect();
+    let array = PyArray1::from_slice(py, &data).into_any();
+    let list = pyo3::types::PyList::new(py, &data)?.into_any();
+
+    let start = std::time::Instant::now();
+    for _ in 0..iterations {
+        let readonly: PyReadonlyArray1<'_, f64> = array.extract()?;
+        let slice = readonly.as_slice().map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        std::hint::black_box(slice.iter().sum::<f64>());
+    }
+    let numpy_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+
+    let start = std::time::Instant::now();
+    for _ in 0..iterations {
+        let values: Vec<f64> = list.extract()?;
+        std::hint::black_box(values.iter().sum::<f64>());
+    }
+    let list_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("n", n)?;
+    dict.set_item("iterations", iterations)?;
+    dict.set_item("numpy_slice_ns", numpy_ns)?;
+    dict.set_item("list_vec_ns", list_ns)?;
+    dict.set_item("speedup", list_ns / numpy_ns)?;
+    Ok(dict.into())
+}
+
+/// Check that a borrowed numpy buffer is still at the same address with the
+/// same contents after being read with the GIL released, the pattern the
+/// GIL-releasing array functions rely on
+#[pyfunction]
+fn verify_buffer_stability(py: Python<'_>, array: numpy::PyReadonlyArray1<'_, f64>) -> PyResult<bool> {
+    let slice = array.as_slice().map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    let ptr_before = slice.as_ptr() as usize;
+    let contents_before = slice.to_vec();
+
+    let sum_without_gil = py.allow_threads(|| {
+        // Give other threads a chance to run while the GIL is free
+        std::thread::yield_now();
+        slice.iter().sum::<f64>()
+    });
+
+    let slice = array.as_slice().map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    Ok(slice.as_ptr() as usize == ptr_before
+        && slice == contents_before.as_slice()
+        && sum_without_gil.to_bits() == contents_before.iter().sum::<f64>().to_bits())
+}
+
+/// Time summing the same `n` i32s with C `sum_int32_array` (i32 accumulator)
+/// vs the safe Rust i64-accumulating sum, both called directly without Python
+#[pyfunction]
+fn benchmark_int_width_sum(py: Python<'_>, n: usize, iterations: usize) -> PyResult<PyObject> {
+    let iterations = iterations.max(1);
+    // Small values keep the i32 accumulator from overflowing
+    let data: Vec<i32> = (0..n).map(|i| (i % 100) as i32).collect();
+
+    let (i32_ns, i64_ns) = py.allow_threads(|| {
+        let start = std::time::Instant::now();
+        for _ in 0..iterations {
+            let arr = std::hint::black_box(&data);
+            std::hint::black_box(unsafe { sum_int32_array(arr.as_ptr(), arr.len()) });
+        }
+        let i32_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+
+        let start = std::time::Instant::now();
+        for _ in 0..iterations {
+            let arr = std::hint::black_box(&data);
+            std::hint::black_box(arr.iter().map(|&x| x as i64).sum::<i64>());
+        }
+        let i64_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+        (i32_ns, i64_ns)
+    });
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("n", n)?;
+    dict.set_item("iterations", iterations)?;
+    dict.set_item("i32_sum_ns", i32_ns)?;
+    dict.set_item("i64_sum_ns", i64_ns)?;
+    dict.set_item("i64_over_i32", i64_ns / i32_ns)?;
+    Ok(dict.into())
+}
+
+// Same work as py_noop, but PyO3 has to parse three defaulted keyword arguments
+#[pyfunction]
+#[pyo3(signature = (a=0, b=0, c=0))]
+fn noop_with_defaults(a: i32, b: i32, c: i32) {
+    let _ = (a, b, c);
+    unsafe { noop() }
+}
+
+/// Compare per-call cost of `py_noop` against a noop with three defaulted keyword args
+#[pyfunction]
+#[pyo3(pass_module)]
+fn benchmark_arg_parsing(m: &Bound<'_, PyModule>, iterations: usize) -> PyResult<PyObject> {
+    let py = m.py();
+    let iterations = iterations.max(1);
+    let plain = m.getattr("py_noop")?;
+    let with_defaults = wrap_pyfunction!(noop_with_defaults, py)?;
+
+    let start = std::time::Instant::now();
+    for _ in 0..iterations {
+        plain.call0()?;
+    }
+    let plain_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+
+    let start = std::time::Instant::now();
+    for _ in 0..iterations {
+        with_defaults.call0()?;
+    }
+    let defaults_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("iterations", iterations)?;
+    dict.set_item("no_args_ns", plain_ns)?;
+    dict.set_item("defaulted_kwargs_ns", defaults_ns)?;
+    dict.set_item("overhead_ns", defaults_ns - plain_ns)?;
+    Ok(dict.into())
+}
+
+// Machine/interpreter facts used to tag benchmark results; invariant per process
+struct EnvironmentFingerprint {
+    cpu_model: String,
+    cpu_cores: usize,
+    total_memory_kb: u64,
+    python_version: String,
+    free_threaded: bool,
+    build_profile: &'static str,
+}
+
+static ENVIRONMENT_FINGERPRINT: OnceLock<EnvironmentFingerprint> = OnceLock::new();
+
+fn proc_field(path: &str, key: &str) -> Option<String> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    contents
+        .lines()
+        .find(|line| line.starts_with(key))
+        .and_then(|line| line.split_once(':'))
+        .map(|(_, value)| value.trim().to_string())
+}
+
+// Free-threaded (Py_GIL_DISABLED) build whose GIL is actually disabled
+fn is_free_threaded(py: Python<'_>) -> PyResult<bool> {
+    let sysconfig = py.import("sysconfig")?;
+    let gil_disabled_build = sysconfig
+        .call_method1("get_config_var", ("Py_GIL_DISABLED",))?
+        .extract::<Option<i64>>()?
+        == Some(1);
+    if !gil_disabled_build {
+        return Ok(false);
+    }
+    // A free-threaded build can still re-enable the GIL at runtime (PYTHON_GIL=1)
+    let sys = py.import("sys")?;
+    if !sys.hasattr("_is_gil_enabled")? {
+        return Ok(true);
+    }
+    Ok(!sys.getattr("_is_gil_enabled")?.call0()?.extract::<bool>()?)
+}
+
+fn collect_environment_fingerprint(py: Python<'_>) -> PyResult<EnvironmentFingerprint> {
+    let free_threaded = is_free_threaded(py)?;
+
+    Ok(EnvironmentFingerprint {
+        cpu_model: proc_field("/proc/cpuinfo", "model name").unwrap_or_else(|| "unknown".to_string()),
+        cpu_cores: std::thread::available_parallelism().map(|p| p.get()).unwrap_or(1),
+        total_memory_kb: proc_field("/proc/meminfo", "MemTotal")
+            .and_then(|value| value.split_whitespace().next()?.parse().ok())
+            .unwrap_or(0),
+        python_version: py.version().split_whitespace().next().unwrap_or("").to_string(),
+        free_threaded,
+        build_profile: if cfg!(debug_assertions) { "debug" } else { "release" },
+    })
+}
+
+/// CPU, memory, interpreter and build facts for tagging benchmark results (computed once)
+#[pyfunction]
+fn environment_fingerprint(py: Python<'_>) -> PyResult<PyObject> {
+    let fingerprint = match ENVIRONMENT_FINGERPRINT.get() {
+        Some(fingerprint) => fingerprint,
+        None => {
+            let collected = collect_environment_fingerprint(py)?;
+            ENVIRONMENT_FINGERPRINT.get_or_init(|| collected)
+        }
+    };
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("cpu_model", &fingerprint.cpu_model)?;
+    dict.set_item("cpu_cores", fingerprint.cpu_cores)?;
+    dict.set_item("total_memory_kb", fingerprint.total_memory_kb)?;
+    dict.set_item("python_version", &fingerprint.python_version)?;
+    dict.set_item("free_threaded", fingerprint.free_threaded)?;
+    dict.set_item("build_profile", fingerprint.build_profile)?;
+    Ok(dict.into())
+}
+
+// Mean ns per call of `func(*args)` through the normal Python call path
+fn time_python_call(func: &Bound<'_, PyAny>, args: &Bound<'_, pyo3::types::PyTuple>, iterations: usize) -> PyResult<f64> {
+    let start = std::time::Instant::now();
+    for _ in 0..iterations {
+        func.call1(args)?;
+    }
+    Ok(start.elapsed().as_nanos() as f64 / iterations as f64)
+}
+
+/// Time PyO3 argument marshalling for two ints, two floats and a 100-element list
+/// `*_marshalling_ns` subtract the zero-argument `py_noop` call as the dispatch baseline
+#[pyfunction]
+#[pyo3(pass_module)]
+fn benchmark_arg_marshalling(m: &Bound<'_, PyModule>, iterations: usize) -> PyResult<PyObject> {
+    let py = m.py();
+    let iterations = iterations.max(1);
+    let values: Vec<f64> = (0..100).map(|i| i as f64).collect();
+
+    let noop_ns = time_python_call(&m.getattr("py_noop")?, &pyo3::types::PyTuple::empty(py), iterations)?;
+    let int32_ns = time_python_call(&m.getattr("py_add_int32")?, &(1i32, 2i32).into_pyobject(py)?, iterations)?;
+    let double_ns = time_python_call(&m.getattr("py_add_double")?, &(1.5f64, 2.5f64).into_pyobject(py)?, iterations)?;
+    let list_ns = time_python_call(
+        &m.getattr("py_sum_doubles_readonly")?,
+        &pyo3::types::PyTuple::new(py, [pyo3::types::PyList::new(py, &values)?])?,
+        iterations,
+    )?;
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("iterations", iterations)?;
+    dict.set_item("noop_ns", noop_ns)?;
+    dict.set_item("int32_args_ns", int32_ns)?;
+    dict.set_item("double_args_ns", double_ns)?;
+    dict.set_item("list_arg_ns", list_ns)?;
+    dict.set_item("int32_marshalling_ns", int32_ns - noop_ns)?;
+    dict.set_item("double_marshalling_ns", double_ns - noop_ns)?;
+    dict.set_item("list_marshalling_ns", list_ns - noop_ns)?;
+    Ok(dict.into())
+}
+
+// Background churn for benchmark_ffi_under_gc_pressure: self-referencing lists
+// are only reclaimable by the cyclic collector, so every batch feeds gen0
+const GC_CHURN_SCRIPT: &std::ffi::CStr = c"
+def churn(stop):
+    while not stop.is_set():
+        junk = []
+        for _ in range(1000):
+            node = []
+            node.append(node)
+            junk.append(node)
+";
+
+// Per-call latency in ns of `func(1, 2)`, sorted ascending
+fn sample_call_latencies(func: &Bound<'_, PyAny>, iterations: usize) -> PyResult<Vec<f64>> {
+    let mut latencies = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = std::time::Instant::now();
+        func.call1((1i32, 2i32))?;
+        latencies.push(start.elapsed().as_nanos() as f64);
+    }
+    latencies.sort_by(|a, b| a.total_cmp(b));
+    Ok(latencies)
+}
+
+// Nearest-rank percentile of an ascending, non-empty sample
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    let rank = ((q / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn gc_collections(gc: &Bound<'_, PyModule>) -> PyResult<u64> {
+    let mut total = 0;
+    for generation in gc.call_method0("get_stats")?.try_iter()? {
+        total += generation?.get_item("collections")?.extract::<u64>()?;
+    }
+    Ok(total)
+}
+
+/// Latency percentiles of `py_add_int32` calls, first quiet and then while a
+/// Python thread churns cyclic garbage, so GIL hand-offs to a collecting
+/// thread show up as tail latency
+#[pyfunction]
+#[pyo3(pass_module)]
+fn benchmark_ffi_under_gc_pressure(m: &Bound<'_, PyModule>, iterations: usize) -> PyResult<PyObject> {
+    let py = m.py();
+    let iterations = iterations.max(1);
+    let add = m.getattr("py_add_int32")?;
+    let gc = py.import("gc")?;
+    let threading = py.import("threading")?;
+
+    let baseline = sample_call_latencies(&add, iterations)?;
+
+    let churn = PyModule::from_code(py, GC_CHURN_SCRIPT, c"gc_churn.py", c"gc_churn")?.getattr("churn")?;
+    let stop = threading.call_method0("Event")?;
+    let kwargs = pyo3::types::PyDict::new(py);
+    kwargs.set_item("target", churn)?;
+    kwargs.set_item("args", (&stop,))?;
+    kwargs.set_item("daemon", true)?;
+    let thread = threading.getattr("Thread")?.call((), Some(&kwargs))?;
+
+    let collections_before = gc_collections(&gc)?;
+    thread.call_method0("start")?;
+    let pressured = sample_call_latencies(&add, iterations);
+    stop.call_method0("set")?;
+    thread.call_method0("join")?;
+    let pressured = pressured?;
+    let collections = gc_collections(&gc)? - collections_before;
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("iterations", iterations)?;
+    dict.set_item("gc_collections", collections)?;
+    for (prefix, sample) in [("baseline", &baseline), ("gc_pressure", &pressured)] {
+        dict.set_item(format!("{}_p50_ns", prefix), percentile(sample, 50.0))?;
+        dict.set_item(format!("{}_p99_ns", prefix), percentile(sample, 99.0))?;
+        dict.set_item(format!("{}_max_ns", prefix), percentile(sample, 100.0))?;
+    }
+    Ok(dict.into())
+}
+
+#[pyfunction]
+fn raise_dimension_error() -> PyResult<()> {
+    Err(DimensionError::new_err("dimension mismatch"))
+}
+
+#[pyfunction]
+fn raise_value_error() -> PyResult<()> {
+    Err(pyo3::exceptions::PyValueError::new_err("dimension mismatch"))
+}
+
+// Mean ns to call `func` and catch the exception it raises
+fn time_raise_catch(func: &Bound<'_, PyAny>, iterations: usize) -> PyResult<f64> {
+    let start = std::time::Instant::now();
+    for _ in 0..iterations {
+        if func.call0().is_ok() {
+            return Err(pyo3::exceptions::PyRuntimeError::new_err("expected an exception"));
+        }
+    }
+    Ok(start.elapsed().as_nanos() as f64 / iterations as f64)
+}
+
+/// Per-raise cost of the custom `DimensionError` versus a built-in `ValueError`
+#[pyfunction]
+fn benchmark_custom_exception(py: Python<'_>, iterations: usize) -> PyResult<PyObject> {
+    let iterations = iterations.max(1);
+    let custom_ns = time_raise_catch(wrap_pyfunction!(raise_dimension_error, py)?.as_any(), iterations)?;
+    let builtin_ns = time_raise_catch(wrap_pyfunction!(raise_value_error, py)?.as_any(), iterations)?;
+
+    let dict = pyo3::types::PyDict::new(py);
+    dict.set_item("iterations", iterations)?;
+    dict.set_item("dimension_error_ns", custom_ns)?;
+    dict.set_item("value_error_ns", builtin_ns)?;
+    dict.set_item("hierarchy_overhead_ns", custom_ns - builtin_ns)?;
+    Ok(dict.into())
+}
+
+/// Sorted names of every function registered in this module (including aliases)
+#[pyfunction]
+#[pyo3(pass_module)]
+fn list_functions(m: &Bound<'_, PyModule>) -> PyResult<Vec<String>> {
+    let mut names = Vec::new();
+    for (name, value) in m.dict().iter() {
+        let name: String = name.extract()?;
+        if !name.starts_with('_') && value.is_instance_of::<pyo3::types::PyCFunction>() {
+            names.push(name);
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Raise RuntimeError if the loaded benchlib reports a different ABI version
+/// than `expected` (default: the version this module was built against)
+#[pyfunction]
+#[pyo3(signature = (expected=BENCHLIB_ABI_VERSION))]
+fn check_benchlib_abi(expected: i32) -> PyResult<()> {
+    let actual = unsafe { benchlib_abi_version() };
+    if actual != expected {
+        return Err(pyo3::exceptions::PyRuntimeError::new_err(format!(
+            "benchlib ABI version mismatch: expected {}, loaded library reports {}",
+            expected, actual
+        )));
+    }
+    Ok(())
+}
+
+// FFI approach comparison
+#[cfg(target_os = "macos")]
+const LIB_SUFFIX: &str = "dylib";
+#[cfg(not(target_os = "macos"))]
+const LIB_SUFFIX: &str = "so";
+
+// First existing `relative` path found walking up from this module's file
+fn find_lib_above(m: &Bound<'_, PyModule>, relatives: &[String], hint: &str) -> PyResult<std::path::PathBuf> {
+    let module_path: String = m.getattr("__file__")?.extract()?;
+    std::path::Path::new(&module_path)
+        .ancestors()
+        .flat_map(|dir| relatives.iter().map(move |relative| dir.join(relative)))
+        .find(|candidate| candidate.exists())
+        .ok_or_else(|| {
+            pyo3::exceptions::PyFileNotFoundError::new_err(format!(
+                "None of {:?} found above {}; {}",
+                relatives, module_path, hint
+            ))
+        })
+}
+
+// rust_ffi/handcrafted_ffi's release build
+fn find_handcrafted_lib(m: &Bound<'_, PyModule>) -> PyResult<std::path::PathBuf> {
+    find_lib_above(
+        m,
+        &[format!("rust_ffi/handcrafted_ffi/target/release/libhandcrafted_ffi.{}", LIB_SUFFIX)],
+        "build rust_ffi/handcrafted_ffi or pass handcrafted_lib_path",
+    )
+}
+
+// benchlib next to this module (the rpath copy) or in benchmark-ffi/lib, as the
+// ctypes and dispatch loaders search for it
+fn find_benchlib(m: &Bound<'_, PyModule>) -> PyResult<std::path::PathBuf> {
+    find_lib_above(
+        m,
+        &[
+            format!("libbenchlib.{}", LIB_SUFFIX),
+            format!("benchlib.{}", LIB_SUFFIX),
+            format!("lib/benchlib.{}", LIB_SUFFIX),
+        ],
+        "run 'make' in benchmark-ffi/lib or pass benchlib_path",
+    )
+}
+
+// ctypes function `name` from `lib` with C int arguments and an int (or void) return
+fn ctypes_function<'py>(
+    ctypes: &Bound<'py, PyModule>,
+    lib: &Bound<'py, PyAny>,
+    name: &str,
+    arg_count: usize,
+    returns_int: bool,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = ctypes.py();
+    let func = lib.getattr(name)?;
+    let c_int = ctypes.getattr("c_int")?;
+    func.setattr("argtypes", pyo3::types::PyList::new(py, vec![c_int.clone(); arg_count])?)?;
+    if returns_int {
+        func.setattr("restype", c_int)?;
+    } else {
+        func.setattr("restype", py.None())?;
+    }
+    Ok(func)
+}
+
+// Loop length for the "compute" workload (c_to_c_baseline and its mirrors)
+const APPROACH_COMPUTE_ITERATIONS: i32 = 100;
+
+/// Per-call ns of three workloads through each FFI approach, from Python:
+/// this PyO3 module, the handcrafted Rust C-ABI library via ctypes and plain C
+/// benchlib via ctypes. Workloads are `noop` (empty call), `add` (two ints in,
+/// one out) and `compute` (c_to_c_baseline(100) or its handcrafted mirror)
+/// Returns `{approach: {"<workload>_ns", "<workload>_relative", ...}}`, each
+/// `relative` being the ratio to the fastest approach for that workload (1.0)
+#[pyfunction]
+#[pyo3(pass_module, signature = (iterations=100000, handcrafted_lib_path=None, benchlib_path=None))]
+fn ffi_approach_table(
+    m: &Bound<'_, PyModule>,
+    iterations: usize,
+    handcrafted_lib_path: Option<std::path::PathBuf>,
+    benchlib_path: Option<std::path::PathBuf>,
+) -> PyResult<PyObject> {
+    let py = m.py();
+    let iterations = iterations.max(1);
+    let ctypes = py.import("ctypes")?;
+    let handcrafted_lib_path = match handcrafted_lib_path {
+        Some(path) => path,
+        None => find_handcrafted_lib(m)?,
+    };
+    let benchlib_path = match benchlib_path {
+        Some(path) => path,
+        None => find_benchlib(m)?,
+    };
+
+    let benchlib = ctypes.getattr("CDLL")?.call1((benchlib_path,))?;
+    let handcrafted = ctypes.getattr("CDLL")?.call1((handcrafted_lib_path,))?;
+
+    let empty = pyo3::types::PyTuple::empty(py);
+    let int_pair = (1i32, 2i32).into_pyobject(py)?;
+    let compute_args = (APPROACH_COMPUTE_ITERATIONS,).into_pyobject(py)?;
+    let workloads = ["noop", "add", "compute"];
+    let approaches = [
+        (
+            "pyo3",
+            [
+                (m.getattr("py_noop")?, &empty),
+                (m.getattr("py_add_int32")?, &int_pair),
+                (m.getattr("py_c_to_c_baseline")?, &compute_args),
+            ],
+        ),
+        (
+            "handcrafted",
+            [
+                (ctypes_function(&ctypes, &handcrafted, "test_noop", 0, false)?, &empty),
+                (ctypes_function(&ctypes, &handcrafted, "test_add_int32", 2, true)?, &int_pair),
+                (ctypes_function(&ctypes, &handcrafted, "test_small_compute", 1, true)?, &compute_args),
+            ],
+        ),
+        (
+            "benchlib_c",
+            [
+                (ctypes_function(&ctypes, &benchlib, "noop", 0, false)?, &empty),
+                (ctypes_function(&ctypes, &benchlib, "add_int32", 2, true)?, &int_pair),
+                (ctypes_function(&ctypes, &benchlib, "c_to_c_baseline", 1, true)?, &compute_args),
+            ],
+        ),
+    ];
+
+    let mut timings = Vec::with_capacity(approaches.len());
+    for (approach, calls) in &approaches {
+        let mut row = [0.0; 3];
+        for (ns, (func, args)) in row.iter_mut().zip(calls) {
+            // One untimed call so first-call symbol resolution isn't counted
+            func.call1(*args)?;
+            *ns = time_python_call(func, args, iterations)?;
+        }
+        timings.push((*approach, row));
+    }
+
+    let table = pyo3::types::PyDict::new(py);
+    for (approach, row) in &timings {
+        let entry = pyo3::types::PyDict::new(py);
+        for (column, workload) in workloads.iter().enumerate() {
+            let fastest = timings.iter().map(|(_, other)| other[column]).fold(f64::INFINITY, f64::min);
+            entry.set_item(format!("{}_ns", workload), row[column])?;
+            entry.set_item(format!("{}_relative", workload), row[column] / fastest)?;
+        }
+        table.set_item(*approach, entry)?;
+    }
+    Ok(table.into())
+}
+
 /// A Python module implemented in Rust.
 #[pymodule]
 fn benchlib_pyo3(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    // The ABI check is left to callers (check_benchlib_abi) so a stale
+    // benchlib still imports for inspection
+    m.add("BENCHLIB_ABI_VERSION", BENCHLIB_ABI_VERSION)?;
+    m.add_function(wrap_pyfunction!(check_benchlib_abi, m)?)?;
+    m.add_function(wrap_pyfunction!(list_functions, m)?)?;
+    m.add_function(wrap_pyfunction!(environment_fingerprint, m)?)?;
+
+    // Exceptions
+    m.add("BenchlibError", m.py().get_type::<BenchlibError>())?;
+    m.add("DimensionError", m.py().get_type::<DimensionError>())?;
+    m.add_function(wrap_pyfunction!(benchmark_custom_exception, m)?)?;
+    
     // Basic operations
     m.add_function(wrap_pyfunction!(py_noop, m)?)?;
+    m.add_function(wrap_pyfunction!(py_c_to_c_baseline, m)?)?;
     m.add_function(wrap_pyfunction!(py_return_int, m)?)?;
     m.add_function(wrap_pyfunction!(py_return_int64, m)?)?;
     
@@ -352,6 +1399,7 @@ fn benchlib_pyo3(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(py_logical_and, m)?)?;
     m.add_function(wrap_pyfunction!(py_logical_or, m)?)?;
     m.add_function(wrap_pyfunction!(py_logical_not, m)?)?;
+    m.add_function(wrap_pyfunction!(bool_abi_probe, m)?)?;
     
     // Floating point operations
     m.add_function(wrap_pyfunction!(py_add_float, m)?)?;
@@ -362,12 +1410,15 @@ fn benchlib_pyo3(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(py_sum_doubles_readonly, m)?)?;
     m.add_function(wrap_pyfunction!(py_scale_doubles_inplace, m)?)?;
     m.add_function(wrap_pyfunction!(py_sum_int32_array, m)?)?;
+    m.add_function(wrap_pyfunction!(py_sum_int32_array_i64, m)?)?;
     m.add_function(wrap_pyfunction!(py_fill_int32_array, m)?)?;
     
     // String operations
     m.add_function(wrap_pyfunction!(py_bytes_length, m)?)?;
     m.add_function(wrap_pyfunction!(py_utf8_length, m)?)?;
     m.add_function(wrap_pyfunction!(py_string_identity, m)?)?;
+    m.add_function(wrap_pyfunction!(py_string_identity_encoded, m)?)?;
+    m.add_function(wrap_pyfunction!(benchmark_string_size_scaling, m)?)?;
     m.add_function(wrap_pyfunction!(py_string_concat, m)?)?;
     m.add_function(wrap_pyfunction!(py_free_string, m)?)?;
     
@@ -386,12 +1437,48 @@ fn benchlib_pyo3(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Memory operations
     m.add_function(wrap_pyfunction!(py_allocate_sized, m)?)?;
     m.add_function(wrap_pyfunction!(py_deallocate, m)?)?;
+    m.add_function(wrap_pyfunction!(py_allocate_sized_checked, m)?)?;
+    m.add_function(wrap_pyfunction!(py_deallocate_checked, m)?)?;
+    m.add_function(wrap_pyfunction!(py_allocate_aligned, m)?)?;
+    m.add_function(wrap_pyfunction!(py_deallocate_aligned, m)?)?;
+    #[cfg(feature = "test-hooks")]
+    m.add_function(wrap_pyfunction!(_poison_aligned_allocations, m)?)?;
+    m.add_function(wrap_pyfunction!(benchmark_tracker_contention, m)?)?;
     
     // Callback operations
     m.add_function(wrap_pyfunction!(py_c_transform, m)?)?;
     m.add_function(wrap_pyfunction!(py_apply_callback, m)?)?;
     m.add_function(wrap_pyfunction!(py_sum_with_transform, m)?)?;
-    
+    m.add_function(wrap_pyfunction!(register_transform, m)?)?;
+    m.add_function(wrap_pyfunction!(apply_transform, m)?)?;
+    m.add_function(wrap_pyfunction!(list_transforms, m)?)?;
+    m.add_function(wrap_pyfunction!(clear_transforms, m)?)?;
+
+    // Cold-start measurements
+    m.add_function(wrap_pyfunction!(benchmark_cold_first_call, m)?)?;
+
+    // GIL contention measurements
+    m.add_function(wrap_pyfunction!(benchmark_gil_vs_released, m)?)?;
+
+    // Argument parsing measurements
+    m.add_function(wrap_pyfunction!(benchmark_arg_parsing, m)?)?;
+    m.add_function(wrap_pyfunction!(benchmark_arg_marshalling, m)?)?;
+
+    // FFI approach comparison
+    m.add_function(wrap_pyfunction!(ffi_approach_table, m)?)?;
+
+    // GC interference measurements
+    m.add_function(wrap_pyfunction!(benchmark_ffi_under_gc_pressure, m)?)?;
+
+    // Array output measurements
+    m.add_function(wrap_pyfunction!(benchmark_np_array_output, m)?)?;
+    m.add_function(wrap_pyfunction!(benchmark_return_container, m)?)?;
+    m.add_function(wrap_pyfunction!(benchmark_numpy_vs_list_extraction, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_buffer_stability, m)?)?;
+
+    // Integer width measurements
+    m.add_function(wrap_pyfunction!(benchmark_int_width_sum, m)?)?;
+
     // Add aliases to match the Python function names
     m.add("noop", wrap_pyfunction!(py_noop, m)?)?;
     m.add("return_int", wrap_pyfunction!(py_return_int, m)?)?;
@@ -408,10 +1495,12 @@ fn benchlib_pyo3(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("sum_doubles_readonly", wrap_pyfunction!(py_sum_doubles_readonly, m)?)?;
     m.add("scale_doubles_inplace", wrap_pyfunction!(py_scale_doubles_inplace, m)?)?;
     m.add("sum_int32_array", wrap_pyfunction!(py_sum_int32_array, m)?)?;
+    m.add("sum_int32_array_i64", wrap_pyfunction!(py_sum_int32_array_i64, m)?)?;
     m.add("fill_int32_array", wrap_pyfunction!(py_fill_int32_array, m)?)?;
     m.add("bytes_length", wrap_pyfunction!(py_bytes_length, m)?)?;
     m.add("utf8_length", wrap_pyfunction!(py_utf8_length, m)?)?;
     m.add("string_identity", wrap_pyfunction!(py_string_identity, m)?)?;
+    m.add("string_identity_encoded", wrap_pyfunction!(py_string_identity_encoded, m)?)?;
     m.add("string_concat", wrap_pyfunction!(py_string_concat, m)?)?;
     m.add("free_string", wrap_pyfunction!(py_free_string, m)?)?;
     m.add("create_simple", wrap_pyfunction!(py_create_simple, m)?)?;
@@ -423,6 +1512,10 @@ fn benchlib_pyo3(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("vector_norm", wrap_pyfunction!(py_vector_norm, m)?)?;
     m.add("allocate_sized", wrap_pyfunction!(py_allocate_sized, m)?)?;
     m.add("deallocate", wrap_pyfunction!(py_deallocate, m)?)?;
+    m.add("allocate_sized_checked", wrap_pyfunction!(py_allocate_sized_checked, m)?)?;
+    m.add("deallocate_checked", wrap_pyfunction!(py_deallocate_checked, m)?)?;
+    m.add("allocate_aligned", wrap_pyfunction!(py_allocate_aligned, m)?)?;
+    m.add("deallocate_aligned", wrap_pyfunction!(py_deallocate_aligned, m)?)?;
     m.add("c_transform", wrap_pyfunction!(py_c_transform, m)?)?;
     m.add("apply_callback", wrap_pyfunction!(py_apply_callback, m)?)?;
     m.add("sum_with_transform", wrap_pyfunction!(py_sum_with_transform, m)?)?;
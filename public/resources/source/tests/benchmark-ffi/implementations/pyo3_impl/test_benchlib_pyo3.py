@@ -0,0 +1,282 @@
+# tests/benchmark-ffi/implementations/pyo3_impl/test_benchlib_pyo3.py
+# Tests for the PyO3 benchlib bindings
+
+import unittest
+import importlib.util
+import platform
+from pathlib import Path
+
+class TestBenchlibPyO3(unittest.TestCase):
+    """Test suite for the benchlib_pyo3 extension module"""
+
+    @classmethod
+    def setUpClass(cls):
+        """Load the built benchlib_pyo3 extension"""
+        impl_dir = Path(__file__).parent
+        lib_ext = ".dylib" if platform.system().lower() == "darwin" else ".so"
+        lib_paths = [
+            impl_dir / "target" / "release" / f"libbenchlib_pyo3{lib_ext}",
+            impl_dir / "target" / "debug" / f"libbenchlib_pyo3{lib_ext}",
+        ]
+
+        cls.module = None
+        for lib_path in lib_paths:
+            if lib_path.exists():
+                try:
+                    spec = importlib.util.spec_from_file_location("benchlib_pyo3", lib_path)
+                    module = importlib.util.module_from_spec(spec)
+                    spec.loader.exec_module(module)
+                    cls.module = module
+                    break
+                except ImportError as e:
+                    print(f"Failed to load {lib_path}: {e}")
+                    continue
+
+        if cls.module is None:
+            raise unittest.SkipTest("benchlib_pyo3 not found. Run 'make build-pyo3' in benchmark-ffi/")
+
+    def test_list_functions(self):
+        """Test the inventory of registered module functions"""
+        names = self.module.list_functions()
+
+        self.assertEqual(names, sorted(names), "Names should be sorted")
+        for name in ("add_int32", "dot_product", "sum_with_transform", "list_functions"):
+            self.assertIn(name, names)
+        self.assertNotIn("SimpleStruct", names, "Classes are not functions")
+
+    def test_environment_fingerprint(self):
+        """Test the cached environment fingerprint"""
+        fingerprint = self.module.environment_fingerprint()
+
+        self.assertGreaterEqual(fingerprint["cpu_cores"], 1)
+        self.assertEqual(fingerprint["python_version"], platform.python_version())
+        self.assertIn(fingerprint["build_profile"], ("debug", "release"))
+        self.assertEqual(self.module.environment_fingerprint(), fingerprint, "Fingerprint should be stable")
+
+    def test_cold_first_call(self):
+        """Test cold first-call measurement in a fresh subprocess"""
+        result = self.module.benchmark_cold_first_call()
+
+        self.assertEqual(result["method"], "subprocess")
+        self.assertGreater(result["noop_first_call_ns"], 0, "Cold noop call should take time")
+        self.assertGreater(result["add_int32_first_call_ns"], 0, "Cold add_int32 call should take time")
+
+    def test_ffi_approach_table(self):
+        """Test the PyO3 / handcrafted / plain C comparison table"""
+        tests_dir = Path(__file__).resolve().parents[3]
+        lib_ext = ".dylib" if platform.system().lower() == "darwin" else ".so"
+        handcrafted = tests_dir / "rust_ffi" / "handcrafted_ffi" / "target" / "release" / f"libhandcrafted_ffi{lib_ext}"
+        if not handcrafted.exists():
+            self.skipTest("handcrafted_ffi not built. Run 'cargo build --release' in rust_ffi/handcrafted_ffi/")
+
+        table = self.module.ffi_approach_table(1000, str(handcrafted))
+
+        self.assertEqual(set(table), {"pyo3", "handcrafted", "benchlib_c"})
+        for workload in ("noop", "add", "compute"):
+            for row in table.values():
+                self.assertGreater(row[f"{workload}_ns"], 0)
+                self.assertGreaterEqual(row[f"{workload}_relative"], 1.0)
+            self.assertEqual(min(row[f"{workload}_relative"] for row in table.values()), 1.0,
+                             f"Fastest {workload} should be normalized to 1.0")
+
+    def test_gil_vs_released_throughput(self):
+        """Test GIL-held vs GIL-released throughput comparison"""
+        result = self.module.benchmark_gil_vs_released(1000, 50, 2)
+
+        self.assertEqual(result["threads"], 2)
+        self.assertGreater(result["gil_held_ops_per_sec"], 0, "GIL-held throughput should be positive")
+        self.assertGreater(result["gil_released_ops_per_sec"], 0, "GIL-released throughput should be positive")
+        self.assertIn("speedup", result, "Speedup ratio should be reported")
+        self.assertGreater(result["speedup"], 0)
+
+    def test_bool_abi_probe(self):
+        """Test the C logical_and/logical_or truth table through the bool ABI"""
+        result = self.module.bool_abi_probe()
+
+        self.assertEqual(result["bool_size"], 1)
+        self.assertEqual(len(result["truth_table"]), 4)
+        for row in result["truth_table"]:
+            self.assertEqual(row["and"], row["a"] and row["b"])
+            self.assertEqual(row["or"], row["a"] or row["b"])
+
+    def test_poisoned_tracker_recovers(self):
+        """Test that a panic while holding the allocation tracker lock doesn't break later calls"""
+        if not hasattr(self.module, "_poison_aligned_allocations"):
+            self.skipTest("Build with --features test-hooks to enable the poisoning hook")
+        self.module._poison_aligned_allocations()
+
+        addr = self.module.py_allocate_aligned(128, 64)
+        self.assertEqual(addr % 64, 0)
+        self.module.py_deallocate_aligned(addr)
+
+    def test_tracker_contention(self):
+        """Test allocation tracker throughput under multi-thread contention"""
+        result = self.module.benchmark_tracker_contention(4, 2000)
+
+        self.assertEqual(result["thread_count"], 4)
+        self.assertGreater(result["ops_per_sec"], 0, "Contended throughput should be reported")
+        self.assertGreater(result["single_thread_ops_per_sec"], 0)
+
+    def test_transform_registry(self):
+        """Test registering, listing and clearing named transforms"""
+        self.module.clear_transforms()
+        self.module.register_transform("triple", lambda x: x * 3)
+        self.module.register_transform("double", lambda x: x * 2)
+
+        self.assertEqual(self.module.list_transforms(), ["double", "triple"])
+        self.assertEqual(self.module.apply_transform("triple", 5), 15)
+
+        self.module.clear_transforms()
+        self.assertEqual(self.module.list_transforms(), [])
+        with self.assertRaises(KeyError):
+            self.module.apply_transform("double", 1)
+
+    def test_arg_parsing_overhead(self):
+        """Test no-arg vs defaulted-kwargs call cost comparison"""
+        result = self.module.benchmark_arg_parsing(10000)
+
+        self.assertGreater(result["no_args_ns"], 0, "No-arg call should take time")
+        self.assertGreater(result["defaulted_kwargs_ns"], 0, "Defaulted-kwargs call should take time")
+        self.assertIn("overhead_ns", result)
+
+    def test_arg_marshalling(self):
+        """Test per-type argument marshalling costs"""
+        result = self.module.benchmark_arg_marshalling(5000)
+
+        for key in ("int32_args_ns", "double_args_ns", "list_arg_ns"):
+            self.assertGreater(result[key], 0, f"{key} should be positive")
+        self.assertGreater(result["list_arg_ns"], result["int32_args_ns"], "List marshalling should be slowest")
+        self.assertGreater(result["list_arg_ns"], result["double_args_ns"], "List marshalling should be slowest")
+
+    def test_ffi_under_gc_pressure(self):
+        """Test FFI call latency percentiles with and without GC pressure"""
+        result = self.module.benchmark_ffi_under_gc_pressure(2000)
+
+        for phase in ("baseline", "gc_pressure"):
+            self.assertGreater(result[f"{phase}_p50_ns"], 0, f"{phase} latency should be reported")
+            self.assertGreaterEqual(result[f"{phase}_p99_ns"], result[f"{phase}_p50_ns"])
+        self.assertGreaterEqual(result["gc_collections"], 0)
+
+    def test_custom_exception_overhead(self):
+        """Test DimensionError vs ValueError raise/catch timings"""
+        if not hasattr(self.module, "DimensionError"):
+            self.skipTest("Custom exception hierarchy not available")
+
+        self.assertTrue(issubclass(self.module.DimensionError, self.module.BenchlibError))
+        result = self.module.benchmark_custom_exception(1000)
+
+        self.assertGreater(result["dimension_error_ns"], 0, "Custom raise should take time")
+        self.assertGreater(result["value_error_ns"], 0, "Built-in raise should take time")
+
+    def test_string_identity_encoded_latin1(self):
+        """Test string round-trip through C with an explicit latin-1 encoding"""
+        original = "café naïve"
+        result = self.module.py_string_identity_encoded(original.encode("latin-1"), "latin-1")
+
+        self.assertEqual(result, original, "latin-1 round-trip should preserve the string")
+
+    def test_string_identity_encoded_unknown_encoding(self):
+        """Test that an unknown encoding raises LookupError"""
+        with self.assertRaises(LookupError):
+            self.module.py_string_identity_encoded(b"abc", "no-such-encoding")
+
+    def test_deallocate_rejects_interior_pointer(self):
+        """Test that deallocate_checked only accepts addresses returned by allocate_sized_checked"""
+        addr = self.module.allocate_sized_checked(256)
+        self.assertNotEqual(addr, 0)
+        try:
+            with self.assertRaisesRegex(ValueError, "address not from allocate_sized"):
+                self.module.deallocate_checked(addr + 8)
+        finally:
+            self.module.deallocate_checked(addr)
+
+        with self.assertRaisesRegex(ValueError, "address not from allocate_sized"):
+            self.module.deallocate_checked(addr)
+
+    def test_allocate_aligned(self):
+        """Test that aligned allocations honour the requested alignment"""
+        addr = self.module.py_allocate_aligned(1000, 64)
+        try:
+            self.assertNotEqual(addr, 0)
+            self.assertEqual(addr % 64, 0, "Address should be 64-byte aligned")
+        finally:
+            self.module.py_deallocate_aligned(addr)
+
+        with self.assertRaises(ValueError):
+            self.module.py_allocate_aligned(64, 48)
+        with self.assertRaises(ValueError):
+            self.module.py_deallocate_aligned(addr)
+
+    def test_simple_struct_bytes_round_trip(self):
+        """Test SimpleStruct serialization to and from the C layout"""
+        original = self.module.SimpleStruct()
+        original.x, original.y, original.value = -7, 42, 3.5
+
+        data = original.to_bytes()
+        self.assertEqual(len(data), 16, "Serialized form should match the 16-byte C layout")
+
+        restored = self.module.SimpleStruct.from_bytes(data)
+        self.assertEqual((restored.x, restored.y, restored.value), (-7, 42, 3.5))
+
+        with self.assertRaises(ValueError):
+            self.module.SimpleStruct.from_bytes(data[:-1])
+
+    def test_int_width_sum(self):
+        """Test i32 C sum vs i64 Rust sum timings"""
+        result = self.module.benchmark_int_width_sum(1000, 100)
+
+        self.assertGreater(result["i32_sum_ns"], 0, "i32 sum should take time")
+        self.assertGreater(result["i64_sum_ns"], 0, "i64 sum should take time")
+        self.assertIn("i64_over_i32", result)
+        self.assertEqual(self.module.sum_int32_array_i64([2**31 - 1, 1]), 2**31)
+
+    def test_string_size_scaling(self):
+        """Test per-length timings for string round-trips"""
+        result = self.module.benchmark_string_size_scaling([16, 1024], 100)
+
+        self.assertIn(1024, result, "Larger size should be reported")
+        self.assertGreater(result[16], 0, "Short string timing should be positive")
+        self.assertGreater(result[1024], 0, "Long string timing should be positive")
+
+    @unittest.skipUnless(importlib.util.find_spec("numpy"), "numpy not installed")
+    def test_np_array_output(self):
+        """Test numpy array vs list output timings"""
+        result = self.module.benchmark_np_array_output(1000, 20)
+
+        self.assertGreater(result["numpy_array_ns"], 0, "numpy output should take time")
+        self.assertGreater(result["python_list_ns"], 0, "list output should take time")
+
+    @unittest.skipUnless(importlib.util.find_spec("numpy"), "numpy not installed")
+    def test_numpy_vs_list_extraction(self):
+        """Test zero-copy numpy input vs list-to-Vec extraction timings"""
+        result = self.module.benchmark_numpy_vs_list_extraction(100000, 20)
+
+        self.assertGreater(result["numpy_slice_ns"], 0, "Zero-copy path should be reported")
+        self.assertLessEqual(result["numpy_slice_ns"], result["list_vec_ns"], "Zero-copy should not be slower")
+
+    @unittest.skipUnless(importlib.util.find_spec("numpy"), "numpy not installed")
+    def test_verify_buffer_stability(self):
+        """Test that a numpy buffer is unchanged across a GIL release"""
+        import numpy as np
+
+        self.assertTrue(self.module.verify_buffer_stability(np.arange(1000, dtype=np.float64)))
+
+    def test_check_benchlib_abi(self):
+        """Test ABI version check against the loaded benchlib"""
+        self.module.check_benchlib_abi(self.module.BENCHLIB_ABI_VERSION)
+        self.module.check_benchlib_abi()
+
+        with self.assertRaises(RuntimeError):
+            self.module.check_benchlib_abi(self.module.BENCHLIB_ABI_VERSION + 1)
+
+    @unittest.skipUnless(importlib.util.find_spec("numpy"), "numpy not installed")
+    def test_return_container(self):
+        """Test list vs numpy copy-out cost for returned arrays"""
+        result = self.module.benchmark_return_container(10000, 20)
+
+        self.assertGreater(result["list_return_ns"], 0, "list return should take time")
+        self.assertGreater(result["numpy_return_ns"], 0, "numpy return should be reported")
+        self.assertIn("copy_out_tax_ns", result)
+
+if __name__ == '__main__':
+    unittest.main(verbosity=2)
@@ -518,4 +518,12 @@ EXPORT LargeReturn create_large_return() {
 // Function to verify library loading
 EXPORT const char* get_library_version() {
     return "benchlib v1.0.0";
-}
\ No newline at end of file
+}
+
+// ABI version for bindings that mirror struct layouts (e.g. SimpleStruct)
+// Bump whenever an exported struct layout or function signature changes
+#define BENCHLIB_ABI_VERSION 1
+
+EXPORT int benchlib_abi_version() {
+    return BENCHLIB_ABI_VERSION;
+}
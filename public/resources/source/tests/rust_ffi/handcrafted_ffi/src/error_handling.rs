@@ -19,6 +19,7 @@ pub enum FFIError {
     PythonException(String),
     MemoryAllocation,
     RustPanic(String),
+    RefCountImbalance(String),
 }
 
 impl fmt::Display for FFIError {
@@ -29,6 +30,7 @@ impl fmt::Display for FFIError {
             FFIError::PythonException(msg) => write!(f, "Python exception: {}", msg),
             FFIError::MemoryAllocation => write!(f, "Memory allocation failed"),
             FFIError::RustPanic(msg) => write!(f, "Rust panic: {}", msg),
+            FFIError::RefCountImbalance(msg) => write!(f, "Reference count imbalance: {}", msg),
         }
     }
 }
@@ -64,6 +66,7 @@ impl ErrorContext {
                 FFIError::PythonException(_) => 3,
                 FFIError::MemoryAllocation => 4,
                 FFIError::RustPanic(_) => 5,
+                FFIError::RefCountImbalance(_) => 6,
             },
             message: message.into_raw(),
             rust_backtrace: backtrace.into_raw(),
@@ -71,6 +74,13 @@ impl ErrorContext {
     }
 }
 
+// Record an error from elsewhere in the crate
+pub(crate) fn set_last_error(error: FFIError) {
+    unsafe {
+        (*std::ptr::addr_of_mut!(ERROR_CONTEXT)).set_error(error);
+    }
+}
+
 // Error handling functions
 #[no_mangle]
 pub extern "C" fn set_error_callback(callback: extern "C" fn(*const RustError)) {
@@ -91,6 +101,26 @@ pub extern "C" fn get_last_error() -> *const RustError {
     }
 }
 
+/// Release a `RustError` returned by `get_last_error`, including its strings.
+///
+/// # Safety
+///
+/// `error` must be null or a pointer obtained from `get_last_error` that has
+/// not already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn free_rust_error(error: *const RustError) {
+    if error.is_null() {
+        return;
+    }
+    let error = Box::from_raw(error as *mut RustError);
+    if !error.message.is_null() {
+        drop(CString::from_raw(error.message));
+    }
+    if !error.rust_backtrace.is_null() {
+        drop(CString::from_raw(error.rust_backtrace));
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn clear_last_error() {
     unsafe {
@@ -4,6 +4,17 @@
 use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_int, c_void};
 use std::ptr;
+use std::sync::{Mutex, MutexGuard};
+
+// Lock global state, recovering the data if a previous holder panicked,
+// so one panic doesn't break every later call into the module
+pub(crate) fn lock_recovering<'a, T>(mutex: &'a Mutex<T>, name: &str) -> MutexGuard<'a, T> {
+    mutex.lock().unwrap_or_else(|poisoned| {
+        eprintln!("Warning: {} lock was poisoned by a panic; recovering its state", name);
+        mutex.clear_poison();
+        poisoned.into_inner()
+    })
+}
 
 // Basic test function to verify FFI works
 #[no_mangle]
@@ -11,6 +22,26 @@ pub extern "C" fn test_function_call() -> c_int {
     42
 }
 
+// Empty call for measuring pure FFI overhead
+#[no_mangle]
+pub extern "C" fn test_noop() {}
+
+// Handcrafted counterpart of benchlib's add_int32: two ints in, one out
+#[no_mangle]
+pub extern "C" fn test_add_int32(a: i32, b: i32) -> i32 {
+    a.wrapping_add(b)
+}
+
+// Mirror of benchlib's c_to_c_baseline: `iterations` real calls summed
+#[no_mangle]
+pub extern "C" fn test_small_compute(iterations: c_int) -> c_int {
+    let mut result: c_int = 0;
+    for _ in 0..iterations {
+        result = result.wrapping_add(std::hint::black_box(test_function_call as extern "C" fn() -> c_int)());
+    }
+    result
+}
+
 // String conversion test function
 #[no_mangle]
 pub extern "C" fn test_string_conversion(input: *const c_char) -> *mut c_char {
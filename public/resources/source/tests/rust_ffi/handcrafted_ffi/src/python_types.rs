@@ -5,8 +5,12 @@ use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_int};
 use std::ptr;
 use std::collections::HashMap;
+use std::sync::Mutex;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+use crate::error_handling::{set_last_error, FFIError};
+use crate::lock_recovering;
+
 // Manual Python C API declarations (subset for testing)
 #[repr(C)]
 pub struct PyObject {
@@ -102,6 +106,81 @@ impl ManualRefCount {
     }
 }
 
+// Process-wide tracker driven through the C API below
+struct SharedTracker(ManualRefCount);
+
+// The raw pointers inside are only identities handed in by C callers; the
+// tracker never dereferences them except on behalf of the caller holding the lock
+unsafe impl Send for SharedTracker {}
+
+static REFCOUNT_TRACKER: Mutex<Option<SharedTracker>> = Mutex::new(None);
+
+// Run `f` on the tracker under its lock, recovering (with a warning) if a
+// previous holder panicked
+fn with_refcount_tracker<R>(f: impl FnOnce(&mut ManualRefCount) -> R) -> R {
+    let mut guard = lock_recovering(&REFCOUNT_TRACKER, "refcount tracker");
+    f(&mut guard.get_or_insert_with(|| SharedTracker(ManualRefCount::new())).0)
+}
+
+/// Record an INCREF of `obj` made at `location`
+///
+/// # Safety
+/// `obj` must be null or point to a live `PyObject` that is not being mutated
+/// elsewhere during the call; `location` must be null or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn manual_refcount_incref(obj: *mut PyObject, location: *const c_char) {
+    if obj.is_null() {
+        return;
+    }
+    let location = if location.is_null() {
+        "<unknown>".to_string()
+    } else {
+        unsafe { CStr::from_ptr(location).to_string_lossy().into_owned() }
+    };
+    with_refcount_tracker(|tracker| tracker.py_incref(obj, &location));
+}
+
+/// Record a DECREF of `obj`; returns 1 when its refcount reached zero
+///
+/// # Safety
+/// `obj` must be null or point to a live `PyObject` that is not being mutated
+/// elsewhere during the call.
+#[no_mangle]
+pub unsafe extern "C" fn manual_refcount_decref(obj: *mut PyObject) -> c_int {
+    if obj.is_null() {
+        return 0;
+    }
+    with_refcount_tracker(|tracker| tracker.py_decref(obj) as c_int)
+}
+
+#[no_mangle]
+pub extern "C" fn manual_refcount_reset() {
+    with_refcount_tracker(|tracker| *tracker = ManualRefCount::new());
+}
+
+// Teardown check: 0 when every INCREF was matched by a DECREF, otherwise the
+// number of outstanding references (or tracked objects) with details recorded
+// in the error context
+#[no_mangle]
+pub extern "C" fn manual_refcount_assert_balanced() -> c_int {
+    with_refcount_tracker(|tracker| {
+        let outstanding_refs = tracker.total_refs.load(Ordering::SeqCst);
+        let imbalance = outstanding_refs.max(tracker.objects.len());
+
+        if imbalance == 0 {
+            return 0;
+        }
+
+        let mut details = tracker.leak_check();
+        if outstanding_refs == 0 {
+            details.push(format!("{} object(s) still tracked with nonzero refcount", tracker.objects.len()));
+        }
+        set_last_error(FFIError::RefCountImbalance(details.join("; ")));
+
+        imbalance.min(c_int::MAX as usize) as c_int
+    })
+}
+
 // Manual string conversion without PyO3
 #[no_mangle]
 pub extern "C" fn manual_string_from_rust(rust_str: *const c_char) -> *mut PyObject {
@@ -0,0 +1,34 @@
+// pyo3_investigation/build.rs
+// Capture the interpreter configuration PyO3 builds against so the module can
+// report at runtime whether it targets the limited API (abi3)
+
+use pyo3_build_config::{BuildFlag, PythonImplementation};
+
+fn main() {
+    let config = pyo3_build_config::get();
+
+    println!("cargo:rustc-check-cfg=cfg(pyo3_limited_api)");
+    if config.abi3 {
+        println!("cargo:rustc-cfg=pyo3_limited_api");
+    }
+
+    // abi3 wheels are tagged with the minimum supported version, e.g. cp38-abi3
+    let prefix = match config.implementation {
+        PythonImplementation::CPython => "cp",
+        PythonImplementation::PyPy => "pp",
+        PythonImplementation::GraalPy => "graalpy",
+    };
+    let free_threaded = config.build_flags.0.contains(&BuildFlag::Other("Py_GIL_DISABLED".to_string()));
+    let tag = if config.abi3 {
+        format!("{}{}{}-abi3", prefix, config.version.major, config.version.minor)
+    } else {
+        format!(
+            "{}{}{}{}",
+            prefix,
+            config.version.major,
+            config.version.minor,
+            if free_threaded { "t" } else { "" }
+        )
+    };
+    println!("cargo:rustc-env=PYO3_INVESTIGATION_ABI_TAG={}", tag);
+}
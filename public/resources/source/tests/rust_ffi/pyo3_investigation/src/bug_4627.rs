@@ -3,6 +3,7 @@
 
 use pyo3::prelude::*;
 use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::thread;
 use std::collections::HashMap;
 
@@ -37,6 +38,26 @@ impl TestSubclass {
     }
 }
 
+// Counts Rust-side drops of TestSubclass, independent of whether __del__ ran;
+// after a balanced run it should equal the number of instances created
+static DROP_COUNT: AtomicU64 = AtomicU64::new(0);
+
+impl Drop for TestSubclass {
+    fn drop(&mut self) {
+        DROP_COUNT.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[pyfunction]
+pub fn subclass_drop_count() -> u64 {
+    DROP_COUNT.load(Ordering::Relaxed)
+}
+
+#[pyfunction]
+pub fn reset_drop_count() {
+    DROP_COUNT.store(0, Ordering::Relaxed);
+}
+
 #[pyfunction]
 pub fn create_test_subclass(data: String) -> PyResult<TestSubclass> {
     let id = std::ptr::addr_of!(data) as u64; // Simple ID generation
@@ -177,4 +198,44 @@ pub fn stress_test_subclass_lifecycle() -> PyResult<HashMap<String, u32>> {
     });
     
     Ok(stats)
-}
\ No newline at end of file
+}
+
+// True on a free-threaded (Py_GIL_DISABLED) build running with the GIL disabled
+#[pyfunction]
+pub fn is_free_threaded(py: Python) -> PyResult<bool> {
+    let sysconfig = py.import_bound("sysconfig")?;
+    let gil_disabled_build = sysconfig
+        .call_method1("get_config_var", ("Py_GIL_DISABLED",))?
+        .extract::<Option<i64>>()?
+        == Some(1);
+    if !gil_disabled_build {
+        return Ok(false);
+    }
+    // A free-threaded build can still re-enable the GIL at runtime (PYTHON_GIL=1)
+    let sys = py.import_bound("sys")?;
+    if !sys.hasattr("_is_gil_enabled")? {
+        return Ok(true);
+    }
+    Ok(!sys.getattr("_is_gil_enabled")?.call0()?.extract::<bool>()?)
+}
+
+// Refcount cost of cloning and dropping a Py<TestSubclass> handle; compare the
+// result across GIL and free-threaded builds (non-atomic vs atomic refcounts)
+#[pyfunction]
+pub fn benchmark_refcount_mode(py: Python, iterations: usize) -> PyResult<PyObject> {
+    let iterations = iterations.max(1);
+    let obj = Py::new(py, TestSubclass::new("refcount".to_string(), 0))?;
+    
+    let start = std::time::Instant::now();
+    for _ in 0..iterations {
+        let handle = obj.clone_ref(py);
+        drop(std::hint::black_box(handle));
+    }
+    let clone_drop_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+    
+    let result = pyo3::types::PyDict::new_bound(py);
+    result.set_item("iterations", iterations)?;
+    result.set_item("clone_drop_ns", clone_drop_ns)?;
+    result.set_item("free_threaded", is_free_threaded(py)?)?;
+    Ok(result.into())
+}
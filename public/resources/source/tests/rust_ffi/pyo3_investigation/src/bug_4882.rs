@@ -108,4 +108,80 @@ pub fn demonstrate_build_cache_corruption() -> PyResult<String> {
     }
     
     Ok(cache_status)
-}
\ No newline at end of file
+}
+
+// Count defined GLOBAL/WEAK entries in the .dynsym table of an ELF64 little-endian image
+fn count_dynamic_symbols(image: &[u8]) -> Option<usize> {
+    const SHT_DYNSYM: u32 = 11;
+    const SYM_SIZE: usize = 24;
+
+    let read_u16 = |off: usize| image.get(off..off + 2).map(|b| u16::from_le_bytes([b[0], b[1]]) as usize);
+    let read_u32 = |off: usize| image.get(off..off + 4).map(|b| u32::from_le_bytes(b.try_into().unwrap()));
+    let read_u64 = |off: usize| image.get(off..off + 8).map(|b| u64::from_le_bytes(b.try_into().unwrap()) as usize);
+
+    // ELF magic, ELFCLASS64, ELFDATA2LSB
+    if image.get(0..6)? != b"\x7fELF\x02\x01" {
+        return None;
+    }
+
+    let sh_off = read_u64(0x28)?;
+    let sh_entsize = read_u16(0x3a)?;
+    let sh_num = read_u16(0x3c)?;
+
+    for i in 0..sh_num {
+        let header = sh_off + i * sh_entsize;
+        if read_u32(header + 4)? != SHT_DYNSYM {
+            continue;
+        }
+        let table_off = read_u64(header + 0x18)?;
+        let table_size = read_u64(header + 0x20)?;
+
+        let count = (0..table_size / SYM_SIZE)
+            .filter(|idx| {
+                let sym = table_off + idx * SYM_SIZE;
+                let binding = image.get(sym + 4).map(|info| info >> 4);
+                let section = read_u16(sym + 6);
+                matches!(binding, Some(1) | Some(2)) && matches!(section, Some(s) if s != 0)
+            })
+            .count();
+        return Some(count);
+    }
+
+    None
+}
+
+#[pyfunction]
+pub fn module_binary_info(py: Python) -> PyResult<PyObject> {
+    // Resolve the shared object containing this very function
+    let mut info: libc::Dl_info = unsafe { std::mem::zeroed() };
+    let found = unsafe { libc::dladdr(module_binary_info as *const libc::c_void, &mut info) };
+    if found == 0 || info.dli_fname.is_null() {
+        return Err(pyo3::exceptions::PyRuntimeError::new_err("dladdr could not locate the extension module"));
+    }
+
+    let path = unsafe { std::ffi::CStr::from_ptr(info.dli_fname) }
+        .to_string_lossy()
+        .into_owned();
+    let size_bytes = std::fs::metadata(&path)?.len();
+    let symbol_count = std::fs::read(&path).ok().and_then(|image| count_dynamic_symbols(&image));
+
+    let dict = pyo3::types::PyDict::new_bound(py);
+    dict.set_item("path", &path)?;
+    dict.set_item("size_bytes", size_bytes)?;
+    dict.set_item("dynamic_symbol_count", symbol_count)?;
+    Ok(dict.into())
+}
+
+// Whether this build targets the limited API (pyo3 `abi3*` feature), as
+// captured by build.rs from pyo3-build-config
+#[pyfunction]
+pub fn uses_limited_api() -> bool {
+    cfg!(pyo3_limited_api)
+}
+
+// Wheel ABI tag the extension was built for: `cp312`, `cp313t` (free-threaded)
+// or `cp38-abi3` for limited API builds
+#[pyfunction]
+pub fn abi_tag() -> &'static str {
+    env!("PYO3_INVESTIGATION_ABI_TAG")
+}
@@ -0,0 +1,115 @@
+// pyo3_investigation/src/ffi_bridge.rs
+// Bridge from the handcrafted C-ABI error context to Python exceptions
+
+use pyo3::exceptions::{PyMemoryError, PyRuntimeError, PyUnicodeError, PyValueError};
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::sync::{Mutex, OnceLock};
+
+use crate::lock_recovering;
+
+// Mirror of handcrafted_ffi::error_handling::RustError
+#[repr(C)]
+struct RustError {
+    error_code: c_int,
+    message: *mut c_char,
+    rust_backtrace: *mut c_char,
+}
+
+type GetLastErrorFn = extern "C" fn() -> *const RustError;
+type ClearLastErrorFn = extern "C" fn();
+type FreeRustErrorFn = unsafe extern "C" fn(*const RustError);
+
+// dlopen handles stored as addresses so the map is Send; never dlclosed
+static LIBRARY_HANDLES: OnceLock<Mutex<HashMap<String, usize>>> = OnceLock::new();
+
+// Open a library once and reuse its handle on later lookups
+fn library_handle(path: &str) -> PyResult<*mut c_void> {
+    let mut handles = lock_recovering(
+        LIBRARY_HANDLES.get_or_init(|| Mutex::new(HashMap::new())),
+        "library handle cache",
+    );
+    if let Some(&handle) = handles.get(path) {
+        return Ok(handle as *mut c_void);
+    }
+
+    let c_path = CString::new(path).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let handle = unsafe { libc::dlopen(c_path.as_ptr(), libc::RTLD_NOW) };
+    if handle.is_null() {
+        return Err(PyRuntimeError::new_err(format!("Could not load handcrafted FFI library {}", path)));
+    }
+    handles.insert(path.to_string(), handle as usize);
+    Ok(handle)
+}
+
+// Look up a symbol in the given library, or in every globally loaded object
+fn lookup_symbol(library_path: Option<&str>, name: &str) -> PyResult<*mut c_void> {
+    let handle = match library_path {
+        Some(path) => library_handle(path)?,
+        None => libc::RTLD_DEFAULT,
+    };
+
+    let c_name = CString::new(name).unwrap();
+    let symbol = unsafe { libc::dlsym(handle, c_name.as_ptr()) };
+    if symbol.is_null() {
+        return Err(PyRuntimeError::new_err(format!(
+            "Symbol {} not found; load handcrafted_ffi with RTLD_GLOBAL or pass its path",
+            name
+        )));
+    }
+    Ok(symbol)
+}
+
+fn c_string_or_empty(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() }
+    }
+}
+
+fn exception_for_code(error_code: c_int, message: String) -> PyErr {
+    match error_code {
+        1 => PyValueError::new_err(message),
+        2 => PyUnicodeError::new_err(message),
+        4 => PyMemoryError::new_err(message),
+        _ => PyRuntimeError::new_err(message),
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (library_path=None))]
+pub fn raise_last_ffi_error(py: Python, library_path: Option<&str>) -> PyResult<()> {
+    let get_last_error: GetLastErrorFn =
+        unsafe { std::mem::transmute(lookup_symbol(library_path, "get_last_error")?) };
+    let clear_last_error: ClearLastErrorFn =
+        unsafe { std::mem::transmute(lookup_symbol(library_path, "clear_last_error")?) };
+    let free_rust_error: FreeRustErrorFn =
+        unsafe { std::mem::transmute(lookup_symbol(library_path, "free_rust_error")?) };
+
+    // The handcrafted side hands out a fresh heap copy; copy it out and free it
+    let error_ptr = get_last_error();
+    if error_ptr.is_null() {
+        return Ok(());
+    }
+    let (error_code, message, backtrace) = {
+        let error = unsafe { &*error_ptr };
+        (error.error_code, c_string_or_empty(error.message), c_string_or_empty(error.rust_backtrace))
+    };
+    unsafe { free_rust_error(error_ptr) };
+
+    // Raising consumes the pending error
+    clear_last_error();
+
+    let err = exception_for_code(error_code, message);
+    err.set_cause(py, Some(PyRuntimeError::new_err(format!("Rust backtrace: {}", backtrace))));
+
+    let value = err.value_bound(py);
+    if value.hasattr("add_note")? {
+        value.call_method1("add_note", (format!("FFI error code: {}", error_code),))?;
+    }
+
+    Err(err)
+}
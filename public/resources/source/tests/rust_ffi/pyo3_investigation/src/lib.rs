@@ -3,6 +3,17 @@
 
 use pyo3::prelude::*;
 use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+// Lock global state, recovering the data if a previous holder panicked,
+// so one panic doesn't break every later call into the module
+pub(crate) fn lock_recovering<'a, T>(mutex: &'a Mutex<T>, name: &str) -> MutexGuard<'a, T> {
+    mutex.lock().unwrap_or_else(|poisoned| {
+        eprintln!("Warning: {} lock was poisoned by a panic; recovering its state", name);
+        mutex.clear_poison();
+        poisoned.into_inner()
+    })
+}
 
 // Basic PyO3 test functions for performance comparison
 #[pyfunction]
@@ -27,6 +38,7 @@ fn create_test_object(size: usize) -> PyResult<HashMap<String, usize>> {
 mod bug_4882;
 mod bug_4627;
 mod performance;
+mod ffi_bridge;
 
 // Python module definition
 #[pymodule]
@@ -39,13 +51,30 @@ fn pyo3_investigation(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Bug reproduction functions
     m.add_function(wrap_pyfunction!(bug_4882::test_abi_cache_poisoning, m)?)?;
     m.add_function(wrap_pyfunction!(bug_4882::demonstrate_build_cache_corruption, m)?)?;
+    m.add_function(wrap_pyfunction!(bug_4882::module_binary_info, m)?)?;
+    m.add_function(wrap_pyfunction!(bug_4882::uses_limited_api, m)?)?;
+    m.add_function(wrap_pyfunction!(bug_4882::abi_tag, m)?)?;
     
     m.add_function(wrap_pyfunction!(bug_4627::reproduce_subclass_gc_flakiness, m)?)?;
     m.add_function(wrap_pyfunction!(bug_4627::stress_test_subclass_lifecycle, m)?)?;
+    m.add_function(wrap_pyfunction!(bug_4627::is_free_threaded, m)?)?;
+    m.add_function(wrap_pyfunction!(bug_4627::benchmark_refcount_mode, m)?)?;
+    m.add_function(wrap_pyfunction!(bug_4627::subclass_drop_count, m)?)?;
+    m.add_function(wrap_pyfunction!(bug_4627::reset_drop_count, m)?)?;
+    
+    // Handcrafted FFI interop
+    m.add_function(wrap_pyfunction!(ffi_bridge::raise_last_ffi_error, m)?)?;
     
     // Performance benchmarks
     m.add_function(wrap_pyfunction!(performance::benchmark_pyo3_overhead, m)?)?;
     m.add_function(wrap_pyfunction!(performance::memory_allocation_benchmark, m)?)?;
+    m.add_function(wrap_pyfunction!(performance::benchmark_exception_overhead, m)?)?;
+    m.add_function(wrap_pyfunction!(performance::benchmark_hashmap_vs_pydict, m)?)?;
+    m.add_function(wrap_pyfunction!(performance::comprehensive_benchmark_suite, m)?)?;
+    m.add_function(wrap_pyfunction!(performance::run_suite_repeated, m)?)?;
+    #[cfg(target_os = "linux")]
+    m.add_function(wrap_pyfunction!(performance::run_benchmark_pinned, m)?)?;
+    m.add_function(wrap_pyfunction!(performance::benchmark_native_thread_gil, m)?)?;
     
     // Add subclass for testing
     m.add_class::<bug_4627::TestSubclass>()?;
@@ -1,11 +1,30 @@
 // pyo3_investigation/src/performance.rs
 // Performance comparison benchmarks between PyO3 and handcrafted FFI
 
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 use std::time::Instant;
 use std::collections::HashMap;
 use crate::bug_4627::TestSubclass;
 
+const FUNCTION_CALL_ITERATIONS: usize = 100_000;
+const OBJECT_ALLOCATION_ITERATIONS: usize = 10_000;
+const STRING_OPERATION_ITERATIONS: usize = 10_000;
+const CALLBACK_ITERATIONS: usize = 1_000;
+const GIL_ACQUISITION_ITERATIONS: usize = 10_000;
+
+// Read VmRSS from /proc/self/status (kB)
+pub(crate) fn rss_kib() -> u64 {
+    let status = std::fs::read_to_string("/proc/self/status").unwrap_or_default();
+    for line in status.lines() {
+        if let Some(rest) = line.strip_prefix("VmRSS:") {
+            return rest.split_whitespace().next().unwrap_or("0").parse::<u64>().unwrap_or(0);
+        }
+    }
+    0
+}
+
 #[pyfunction]
 pub fn pyo3_function_call_test() -> i32 {
     42
@@ -19,7 +38,7 @@ pub fn pyo3_string_conversion_test(input: String) -> String {
 #[pyfunction]
 pub fn benchmark_pyo3_overhead() -> PyResult<Vec<(String, f64)>> {
     let mut results = Vec::new();
-    let iterations = 100_000;
+    let iterations = FUNCTION_CALL_ITERATIONS;
     
     // Basic function call benchmark
     let start = Instant::now();
@@ -44,7 +63,7 @@ pub fn benchmark_pyo3_overhead() -> PyResult<Vec<(String, f64)>> {
 #[pyfunction]
 pub fn memory_allocation_benchmark() -> PyResult<HashMap<String, f64>> {
     let mut results = HashMap::new();
-    let iterations = 10_000;
+    let iterations = OBJECT_ALLOCATION_ITERATIONS;
     
     // PyO3 object creation
     let start = Instant::now();
@@ -85,7 +104,7 @@ pub fn benchmark_string_operations(input: String, iterations: usize) -> PyResult
 #[pyfunction]
 pub fn benchmark_callback_performance() -> PyResult<HashMap<String, f64>> {
     let mut results = HashMap::new();
-    let iterations = 1_000;
+    let iterations = CALLBACK_ITERATIONS;
     
     Python::with_gil(|py| -> PyResult<()> {
         // Create a simple callable that simulates callback overhead
@@ -110,7 +129,7 @@ pub fn benchmark_callback_performance() -> PyResult<HashMap<String, f64>> {
 #[pyfunction]
 pub fn benchmark_gil_acquisition() -> PyResult<HashMap<String, f64>> {
     let mut results = HashMap::new();
-    let iterations = 10_000;
+    let iterations = GIL_ACQUISITION_ITERATIONS;
     
     // Benchmark GIL acquisition overhead
     let start = Instant::now();
@@ -126,22 +145,285 @@ pub fn benchmark_gil_acquisition() -> PyResult<HashMap<String, f64>> {
     Ok(results)
 }
 
+// Run one benchmark category and record RSS around it
+fn with_rss_tracking<F>(ops: usize, benchmark: F) -> PyResult<HashMap<String, f64>>
+where
+    F: FnOnce() -> PyResult<HashMap<String, f64>>,
+{
+    let rss_before = rss_kib();
+    let mut results = benchmark()?;
+    let rss_after = rss_kib();
+    
+    results.insert("rss_before_mib".to_string(), rss_before as f64 / 1024.0);
+    results.insert("rss_after_mib".to_string(), rss_after as f64 / 1024.0);
+    results.insert(
+        "rss_delta_kb_per_op".to_string(),
+        (rss_after as f64 - rss_before as f64) / ops.max(1) as f64,
+    );
+    
+    Ok(results)
+}
+
 #[pyfunction]
 pub fn comprehensive_benchmark_suite() -> PyResult<HashMap<String, HashMap<String, f64>>> {
     let mut suite_results = HashMap::new();
     
     // Run all benchmark categories
-    let function_results = benchmark_pyo3_overhead()?;
-    let mut function_map = HashMap::new();
-    for (key, value) in function_results {
-        function_map.insert(key, value);
-    }
+    let function_map = with_rss_tracking(2 * FUNCTION_CALL_ITERATIONS, || {
+        Ok(benchmark_pyo3_overhead()?.into_iter().collect())
+    })?;
     suite_results.insert("function_calls".to_string(), function_map);
     
-    suite_results.insert("memory_allocation".to_string(), memory_allocation_benchmark()?);
-    suite_results.insert("string_operations".to_string(), benchmark_string_operations("test".to_string(), 10000)?);
-    suite_results.insert("callback_performance".to_string(), benchmark_callback_performance()?);
-    suite_results.insert("gil_acquisition".to_string(), benchmark_gil_acquisition()?);
+    suite_results.insert(
+        "memory_allocation".to_string(),
+        with_rss_tracking(OBJECT_ALLOCATION_ITERATIONS, memory_allocation_benchmark)?,
+    );
+    suite_results.insert(
+        "string_operations".to_string(),
+        with_rss_tracking(STRING_OPERATION_ITERATIONS, || {
+            benchmark_string_operations("test".to_string(), STRING_OPERATION_ITERATIONS)
+        })?,
+    );
+    suite_results.insert(
+        "callback_performance".to_string(),
+        with_rss_tracking(CALLBACK_ITERATIONS, benchmark_callback_performance)?,
+    );
+    suite_results.insert(
+        "gil_acquisition".to_string(),
+        with_rss_tracking(GIL_ACQUISITION_ITERATIONS, benchmark_gil_acquisition)?,
+    );
     
     Ok(suite_results)
-}
\ No newline at end of file
+}
+
+// category -> metric -> {"mean", "stddev", "cv", "runs"}
+type SuiteStats = HashMap<String, HashMap<String, HashMap<String, f64>>>;
+
+// Run the suite `runs` times (the first is discarded as warmup) and report each
+// metric's mean, population standard deviation and coefficient of variation
+// RSS figures from with_rss_tracking are left out: their near-zero, sign-flipping
+// means make the CV meaningless
+#[pyfunction]
+pub fn run_suite_repeated(runs: usize) -> PyResult<SuiteStats> {
+    if runs < 2 {
+        return Err(PyValueError::new_err("runs must be at least 2 (the first run is warmup)"));
+    }
+    
+    comprehensive_benchmark_suite()?;
+    let mut samples: HashMap<String, HashMap<String, Vec<f64>>> = HashMap::new();
+    for _ in 1..runs {
+        for (category, metrics) in comprehensive_benchmark_suite()? {
+            let category_samples = samples.entry(category).or_default();
+            for (metric, value) in metrics.into_iter().filter(|(metric, _)| !metric.starts_with("rss_")) {
+                category_samples.entry(metric).or_default().push(value);
+            }
+        }
+    }
+    
+    let mut aggregated = HashMap::new();
+    for (category, metrics) in samples {
+        let mut category_stats = HashMap::new();
+        for (metric, values) in metrics {
+            let n = values.len() as f64;
+            let mean = values.iter().sum::<f64>() / n;
+            let stddev = (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n).sqrt();
+            let cv = if mean.abs() > f64::EPSILON { stddev / mean.abs() } else { 0.0 };
+            
+            let mut stats = HashMap::new();
+            stats.insert("mean".to_string(), mean);
+            stats.insert("stddev".to_string(), stddev);
+            stats.insert("cv".to_string(), cv);
+            stats.insert("runs".to_string(), n);
+            category_stats.insert(metric, stats);
+        }
+        aggregated.insert(category, category_stats);
+    }
+    
+    Ok(aggregated)
+}
+
+// Run the suite with the calling thread pinned to one core, then restore its affinity
+#[cfg(target_os = "linux")]
+#[pyfunction]
+pub fn run_benchmark_pinned(core: usize) -> PyResult<HashMap<String, HashMap<String, f64>>> {
+    let cpu_count = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_CONF) }.max(1) as usize;
+    if core >= cpu_count.min(libc::CPU_SETSIZE as usize) {
+        return Err(PyValueError::new_err(format!(
+            "core {} out of range (system has {} CPUs)",
+            core, cpu_count
+        )));
+    }
+    
+    let mut original: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    let set_size = std::mem::size_of::<libc::cpu_set_t>();
+    if unsafe { libc::sched_getaffinity(0, set_size, &mut original) } != 0 {
+        return Err(std::io::Error::last_os_error().into());
+    }
+    
+    let mut pinned: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    unsafe { libc::CPU_SET(core, &mut pinned) };
+    if unsafe { libc::sched_setaffinity(0, set_size, &pinned) } != 0 {
+        return Err(std::io::Error::last_os_error().into());
+    }
+    
+    let results = comprehensive_benchmark_suite();
+    
+    // Restore before propagating any benchmark error
+    if unsafe { libc::sched_setaffinity(0, set_size, &original) } != 0 {
+        return Err(std::io::Error::last_os_error().into());
+    }
+    
+    results
+}
+
+#[pyfunction]
+fn raise_value_error_test() -> PyResult<()> {
+    Err(PyValueError::new_err("benchmark validation error"))
+}
+
+#[pyfunction]
+fn ok_value_test() -> PyResult<()> {
+    Ok(())
+}
+
+// Time `iterations` calls of `func` from a Python loop wrapped in try/except
+fn time_python_try_loop(py: Python, func: &Bound<PyAny>, iterations: usize) -> PyResult<f64> {
+    let globals = PyDict::new_bound(py);
+    globals.set_item("func", func)?;
+    globals.set_item("iterations", iterations)?;
+    
+    let start = Instant::now();
+    py.run_bound(
+        "for _ in range(iterations):\n    try:\n        func()\n    except ValueError:\n        pass\n",
+        Some(&globals),
+        None,
+    )?;
+    Ok(start.elapsed().as_nanos() as f64 / iterations as f64)
+}
+
+#[pyfunction]
+pub fn benchmark_exception_overhead(iterations: usize) -> PyResult<HashMap<String, f64>> {
+    let mut results = HashMap::new();
+    let iterations = iterations.max(1);
+    
+    Python::with_gil(|py| -> PyResult<()> {
+        // Construction only: build the PyErr and force the exception object into existence
+        let start = Instant::now();
+        for _ in 0..iterations {
+            let err = PyValueError::new_err("benchmark validation error");
+            let _value = err.value_bound(py);
+        }
+        let construct_time = start.elapsed().as_nanos() as f64 / iterations as f64;
+        results.insert("pyo3_exception_construct_ns".to_string(), construct_time);
+        
+        // Same Python try/except loop, with and without the Rust side raising
+        let raising = wrap_pyfunction_bound!(raise_value_error_test, py)?;
+        let succeeding = wrap_pyfunction_bound!(ok_value_test, py)?;
+        let raise_time = time_python_try_loop(py, raising.as_any(), iterations)?;
+        let ok_time = time_python_try_loop(py, succeeding.as_any(), iterations)?;
+        
+        results.insert("pyo3_exception_raise_catch_ns".to_string(), raise_time);
+        results.insert("pyo3_ok_call_ns".to_string(), ok_time);
+        results.insert("pyo3_exception_overhead_ns".to_string(), (raise_time - ok_time).max(0.0));
+        
+        Ok(())
+    })?;
+    
+    Ok(results)
+}
+
+// Native (non-Python) threads contending for the GIL
+#[pyfunction]
+pub fn benchmark_native_thread_gil(py: Python, thread_count: usize, iterations: usize) -> PyResult<HashMap<String, f64>> {
+    let thread_count = thread_count.max(1);
+    let iterations = iterations.max(1);
+    
+    // The caller must release the GIL or the workers can never acquire it
+    let (elapsed, latencies) = py.allow_threads(|| {
+        let start = Instant::now();
+        let handles: Vec<_> = (0..thread_count)
+            .map(|_| {
+                std::thread::spawn(move || {
+                    let mut total_ns = 0u128;
+                    let mut max_ns = 0u128;
+                    for _ in 0..iterations {
+                        let acquire_start = Instant::now();
+                        Python::with_gil(|_py| {
+                            let elapsed = acquire_start.elapsed().as_nanos();
+                            total_ns += elapsed;
+                            max_ns = max_ns.max(elapsed);
+                        });
+                    }
+                    (total_ns, max_ns)
+                })
+            })
+            .collect();
+        // Join every worker before inspecting results so none outlive the call
+        let joined: Vec<_> = handles.into_iter().map(|h| h.join()).collect();
+        (start.elapsed(), joined)
+    });
+    let latencies: Vec<(u128, u128)> = latencies
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .map_err(|_| PyRuntimeError::new_err("GIL benchmark worker thread panicked"))?;
+    
+    let total_acquisitions = (thread_count * iterations) as f64;
+    let total_latency_ns: u128 = latencies.iter().map(|(total, _)| total).sum();
+    let max_latency_ns = latencies.iter().map(|(_, max)| *max).max().unwrap_or(0);
+    
+    let mut results = HashMap::new();
+    results.insert("thread_count".to_string(), thread_count as f64);
+    results.insert("iterations".to_string(), iterations as f64);
+    results.insert("total_acquisitions".to_string(), total_acquisitions);
+    results.insert("elapsed_s".to_string(), elapsed.as_secs_f64());
+    results.insert("acquisitions_per_sec".to_string(), total_acquisitions / elapsed.as_secs_f64().max(f64::EPSILON));
+    results.insert("mean_acquisition_latency_ns".to_string(), total_latency_ns as f64 / total_acquisitions);
+    results.insert("max_acquisition_latency_ns".to_string(), max_latency_ns as f64);
+    
+    Ok(results)
+}
+
+// `entries` key -> index pairs, returned as a Rust HashMap for PyO3 to convert
+#[pyfunction]
+fn entries_as_hashmap(entries: usize) -> HashMap<String, usize> {
+    (0..entries).map(|i| (format!("key_{}", i), i)).collect()
+}
+
+// The same pairs written straight into a PyDict
+#[pyfunction]
+fn entries_as_pydict(py: Python, entries: usize) -> PyResult<Bound<PyDict>> {
+    let dict = PyDict::new_bound(py);
+    for i in 0..entries {
+        dict.set_item(format!("key_{}", i), i)?;
+    }
+    Ok(dict)
+}
+
+// Per-call cost of returning a HashMap (converted by PyO3) vs building a PyDict,
+// both called through Python so the return conversion is included
+#[pyfunction]
+pub fn benchmark_hashmap_vs_pydict(py: Python, entries: usize, iterations: usize) -> PyResult<HashMap<String, f64>> {
+    let iterations = iterations.max(1);
+    let as_hashmap = wrap_pyfunction_bound!(entries_as_hashmap, py)?;
+    let as_pydict = wrap_pyfunction_bound!(entries_as_pydict, py)?;
+    
+    let start = Instant::now();
+    for _ in 0..iterations {
+        as_hashmap.call1((entries,))?;
+    }
+    let hashmap_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+    
+    let start = Instant::now();
+    for _ in 0..iterations {
+        as_pydict.call1((entries,))?;
+    }
+    let pydict_ns = start.elapsed().as_nanos() as f64 / iterations as f64;
+    
+    let mut results = HashMap::new();
+    results.insert("entries".to_string(), entries as f64);
+    results.insert("iterations".to_string(), iterations as f64);
+    results.insert("hashmap_return_ns".to_string(), hashmap_ns);
+    results.insert("pydict_build_ns".to_string(), pydict_ns);
+    results.insert("hashmap_over_pydict".to_string(), hashmap_ns / pydict_ns);
+    Ok(results)
+}
@@ -7,6 +7,10 @@ import sys
 import os
 from pathlib import Path
 
+class ManualPyObject(ctypes.Structure):
+    """Mirror of the handcrafted PyObject header (ob_refcnt, ob_type)"""
+    _fields_ = [("ob_refcnt", ctypes.c_ssize_t), ("ob_type", ctypes.c_void_p)]
+
 class TestHandcraftedFFI(unittest.TestCase):
     """Test suite for handcrafted FFI implementation without PyO3"""
     
@@ -89,6 +93,19 @@ class TestHandcraftedFFI(unittest.TestCase):
         cls.lib.release_shared_object.argtypes = [ctypes.c_void_p]
         cls.lib.release_shared_object.restype = None
         
+        # Manual reference count tracking
+        cls.lib.manual_refcount_incref.argtypes = [ctypes.c_void_p, ctypes.c_char_p]
+        cls.lib.manual_refcount_incref.restype = None
+        
+        cls.lib.manual_refcount_decref.argtypes = [ctypes.c_void_p]
+        cls.lib.manual_refcount_decref.restype = ctypes.c_int
+        
+        cls.lib.manual_refcount_reset.argtypes = []
+        cls.lib.manual_refcount_reset.restype = None
+        
+        cls.lib.manual_refcount_assert_balanced.argtypes = []
+        cls.lib.manual_refcount_assert_balanced.restype = ctypes.c_int
+        
     def test_basic_function_call(self):
         """Test basic function call overhead - TDD Red→Green→Refactor"""
         # RED: Write a failing test
@@ -225,6 +242,38 @@ class TestHandcraftedFFI(unittest.TestCase):
         error_ptr = self.lib.get_last_error()
         # After clearing, should return null or indicate no error
         
+    def test_refcount_balanced_sequence(self):
+        """Test refcount teardown check passes for matched INCREF/DECREF"""
+        self.lib.manual_refcount_reset()
+        obj = ManualPyObject(0, None)
+        
+        self.lib.manual_refcount_incref(ctypes.byref(obj), b"test_balanced:1")
+        self.lib.manual_refcount_incref(ctypes.byref(obj), b"test_balanced:2")
+        self.lib.manual_refcount_decref(ctypes.byref(obj))
+        self.lib.manual_refcount_decref(ctypes.byref(obj))
+        
+        self.assertEqual(obj.ob_refcnt, 0, "Refcount should return to zero")
+        self.assertEqual(self.lib.manual_refcount_assert_balanced(), 0, "Balanced sequence should report 0")
+        
+    def test_refcount_unbalanced_sequence(self):
+        """Test refcount teardown check detects a missing DECREF"""
+        self.lib.manual_refcount_reset()
+        self.lib.clear_last_error()
+        obj = ManualPyObject(0, None)
+        
+        self.lib.manual_refcount_incref(ctypes.byref(obj), b"test_unbalanced:1")
+        self.lib.manual_refcount_incref(ctypes.byref(obj), b"test_unbalanced:2")
+        self.lib.manual_refcount_decref(ctypes.byref(obj))
+        
+        imbalance = self.lib.manual_refcount_assert_balanced()
+        self.assertNotEqual(imbalance, 0, "Missing DECREF should be reported")
+        
+        error_ptr = self.lib.get_last_error()
+        self.assertIsNotNone(error_ptr, "Imbalance details should be recorded in the error context")
+        
+        self.lib.manual_refcount_reset()
+        self.lib.clear_last_error()
+        
 class TestHandcraftedFFIPerformance(unittest.TestCase):
     """Performance-focused tests for handcrafted FFI"""
     
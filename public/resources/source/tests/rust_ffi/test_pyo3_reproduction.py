@@ -3,7 +3,9 @@
 
 import unittest
 import sys
+import math
 import os
+import sysconfig
 import subprocess
 import threading
 import time
@@ -89,6 +91,57 @@ class TestPyO3BugReproduction(unittest.TestCase):
         if result.error_message:
             print(f"Errors: {result.error_message}")
             
+    def test_module_binary_info(self):
+        """Test shared object path/size reporting for the ABI investigation"""
+        import importlib.machinery
+        
+        info = self.pyo3_module.module_binary_info()
+        
+        self.assertTrue(info["path"].endswith(tuple(importlib.machinery.EXTENSION_SUFFIXES)),
+                        f"Unexpected extension path: {info['path']}")
+        self.assertGreater(info["size_bytes"], 0, "Binary size should be positive")
+        
+        print(f"Module binary: {info['path']} ({info['size_bytes']} bytes, "
+              f"{info['dynamic_symbol_count']} dynamic symbols)")
+            
+    def test_limited_api_detection(self):
+        """Test reporting of the abi3/limited API build setting and ABI tag"""
+        limited = self.pyo3_module.uses_limited_api()
+        tag = self.pyo3_module.abi_tag()
+        
+        self.assertIsInstance(limited, bool)
+        self.assertIsInstance(tag, str)
+        self.assertTrue(tag, "ABI tag should be non-empty")
+        self.assertEqual(tag.endswith("abi3"), limited)
+        
+    def test_raise_last_ffi_error(self):
+        """Test handcrafted FFI errors are re-raised as Python exceptions"""
+        import ctypes
+        
+        handcrafted_dir = Path(__file__).parent / "handcrafted_ffi" / "target" / "release"
+        lib_paths = [handcrafted_dir / "libhandcrafted_ffi.so", handcrafted_dir / "libhandcrafted_ffi.dylib"]
+        lib_path = next((p for p in lib_paths if p.exists()), None)
+        if lib_path is None:
+            self.skipTest("Handcrafted FFI library not built")
+            
+        lib = ctypes.CDLL(str(lib_path), mode=ctypes.RTLD_GLOBAL)
+        lib.safe_string_operation.argtypes = [ctypes.c_char_p]
+        lib.safe_string_operation.restype = ctypes.c_char_p
+        lib.clear_last_error()
+        
+        # No pending error -> None
+        self.assertIsNone(self.pyo3_module.raise_last_ffi_error(str(lib_path)))
+        
+        # NullPointer (code 1) maps to ValueError
+        lib.safe_string_operation(None)
+        with self.assertRaises(ValueError) as ctx:
+            self.pyo3_module.raise_last_ffi_error(str(lib_path))
+        self.assertIn("Null pointer", str(ctx.exception))
+        self.assertIsNotNone(ctx.exception.__cause__, "Backtrace should be attached as __cause__")
+        
+        # Raising consumes the error
+        self.assertIsNone(self.pyo3_module.raise_last_ffi_error(str(lib_path)))
+            
     def test_bug_4627_subclass_gc_flakiness(self):
         """Test Bug #4627: Subclass + GC flakiness under free-threaded Python"""
         result = self.bug_tester.test_bug_4627_subclass_gc_flakiness()
@@ -119,6 +172,19 @@ class TestPyO3BugReproduction(unittest.TestCase):
             # Some functions might not be exported, that's okay for this test
             pass
             
+    def test_subclass_drop_count(self):
+        """Test that every TestSubclass instance created is dropped exactly once"""
+        import gc
+        count = 200
+        
+        self.pyo3_module.reset_drop_count()
+        objects = [self.pyo3_module.TestSubclass(f"drop_{i}", i) for i in range(count)]
+        self.assertEqual(self.pyo3_module.subclass_drop_count(), 0, "Live objects should not be dropped")
+        
+        del objects
+        gc.collect()
+        self.assertEqual(self.pyo3_module.subclass_drop_count(), count)
+        
     def test_pyo3_performance_benchmarks(self):
         """Test PyO3 performance benchmark functionality"""
         try:
@@ -139,6 +205,90 @@ class TestPyO3BugReproduction(unittest.TestCase):
             # Benchmark functions might not be exported
             pass
             
+    def test_exception_overhead_benchmark(self):
+        """Test PyO3 exception raise/catch overhead measurement"""
+        result = self.pyo3_module.benchmark_exception_overhead(1000)
+        
+        self.assertGreater(result["pyo3_exception_raise_catch_ns"], 0, "Raise+catch should take time")
+        self.assertGreater(result["pyo3_exception_construct_ns"], 0, "Construction should take time")
+        self.assertGreaterEqual(result["pyo3_exception_overhead_ns"], 0)
+        
+        print(f"PyO3 raise+catch: {result['pyo3_exception_raise_catch_ns']:.2f}ns "
+              f"(ok call: {result['pyo3_ok_call_ns']:.2f}ns)")
+        
+    def test_hashmap_vs_pydict_benchmark(self):
+        """Test HashMap-return vs direct PyDict-build timings"""
+        result = self.pyo3_module.benchmark_hashmap_vs_pydict(16, 1000)
+        
+        self.assertGreater(result["hashmap_return_ns"], 0, "HashMap return should take time")
+        self.assertGreater(result["pydict_build_ns"], 0, "PyDict build should take time")
+        
+        print(f"HashMap return: {result['hashmap_return_ns']:.2f}ns, "
+              f"PyDict build: {result['pydict_build_ns']:.2f}ns")
+        
+    @unittest.skipUnless(sys.platform.startswith("linux"), "sched_setaffinity is Linux-only")
+    def test_run_benchmark_pinned(self):
+        """Test running the benchmark suite pinned to core 0"""
+        affinity_before = os.sched_getaffinity(0)
+        suite = self.pyo3_module.run_benchmark_pinned(0)
+        
+        self.assertIn("function_calls", suite, "Pinned run should return suite results")
+        self.assertEqual(os.sched_getaffinity(0), affinity_before, "Affinity should be restored")
+        
+        with self.assertRaises(ValueError):
+            self.pyo3_module.run_benchmark_pinned(os.cpu_count() + 1)
+        
+    def test_refcount_mode_benchmark(self):
+        """Test Py<T> clone/drop refcount timing tagged with the build mode"""
+        result = self.pyo3_module.benchmark_refcount_mode(10000)
+        
+        gil_disabled = (sysconfig.get_config_var("Py_GIL_DISABLED") == 1
+                        and not (hasattr(sys, '_is_gil_enabled') and sys._is_gil_enabled()))
+        self.assertGreater(result["clone_drop_ns"], 0, "Clone+drop should take time")
+        self.assertEqual(result["free_threaded"], gil_disabled)
+        
+        print(f"Py<T> clone+drop: {result['clone_drop_ns']:.2f}ns "
+              f"(free-threaded: {result['free_threaded']})")
+        
+    def test_native_thread_gil_benchmark(self):
+        """Test GIL acquisition from native threads under contention"""
+        result = self.pyo3_module.benchmark_native_thread_gil(2, 100)
+        
+        self.assertEqual(result["total_acquisitions"], 200)
+        self.assertGreater(result["mean_acquisition_latency_ns"], 0, "Acquisition latency should be positive")
+        self.assertGreater(result["acquisitions_per_sec"], 0)
+        
+        print(f"Native-thread GIL acquisition: {result['mean_acquisition_latency_ns']:.2f}ns mean "
+              f"({result['acquisitions_per_sec']:.0f} acquisitions/s)")
+        
+    def test_benchmark_suite_memory_tracking(self):
+        """Test per-category RSS tracking in the comprehensive benchmark suite"""
+        suite = self.pyo3_module.comprehensive_benchmark_suite()
+        
+        self.assertGreater(len(suite), 0, "Suite should report categories")
+        for category, metrics in suite.items():
+            for key in ("rss_before_mib", "rss_after_mib", "rss_delta_kb_per_op"):
+                self.assertIn(key, metrics, f"{category} should report {key}")
+            self.assertTrue(math.isfinite(metrics["rss_delta_kb_per_op"]),
+                            f"{category} RSS delta should be finite")
+        
+    def test_run_suite_repeated_stability(self):
+        """Test mean/CV aggregation across repeated suite runs"""
+        aggregate = self.pyo3_module.run_suite_repeated(3)
+        
+        self.assertIn("function_calls", aggregate, "Function-call category should be aggregated")
+        timings = {k: v for k, v in aggregate["function_calls"].items() if k.endswith("_ns")}
+        self.assertGreater(len(timings), 0, "Function-call timings should be aggregated")
+        for metric, stats in timings.items():
+            self.assertEqual(stats["runs"], 2, "First run should be discarded as warmup")
+            self.assertGreaterEqual(stats["cv"], 0.0, f"{metric} CV should be non-negative")
+            self.assertLessEqual(stats["cv"], 1.0, f"{metric} CV should be at most 1")
+        for category in aggregate.values():
+            self.assertFalse(any(k.startswith("rss_") for k in category), "RSS metrics should be excluded")
+        
+        with self.assertRaises(ValueError):
+            self.pyo3_module.run_suite_repeated(1)
+        
     def test_gil_status_detection(self):
         """Test GIL status detection in bug tester"""
         gil_disabled = self.bug_tester.gil_disabled